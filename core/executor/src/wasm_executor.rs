@@ -599,6 +599,152 @@ impl_function_executor!(this: FunctionExecutor<'e, E>,
 
 		Ok(())
 	},
+	ext_metric_increment(name_data: *const u8, name_len: u32, by: u64) => {
+		let name = this.memory.get(name_data, name_len as usize)
+			.map_err(|_| UserError("OOB while ext_metric_increment: wasm"))?;
+		let name = String::from_utf8(name)
+			.map_err(|_| UserError("Invalid utf8 name passed to ext_metric_increment"))?;
+
+		this.ext.metric_increment(&name, by)
+			.map_err(|_| UserError("Calling unavailable API ext_metric_increment: wasm"))?;
+
+		Ok(())
+	},
+	ext_metric_set(name_data: *const u8, name_len: u32, value: u64) => {
+		let name = this.memory.get(name_data, name_len as usize)
+			.map_err(|_| UserError("OOB while ext_metric_set: wasm"))?;
+		let name = String::from_utf8(name)
+			.map_err(|_| UserError("Invalid utf8 name passed to ext_metric_set"))?;
+
+		this.ext.metric_set(&name, value)
+			.map_err(|_| UserError("Calling unavailable API ext_metric_set: wasm"))?;
+
+		Ok(())
+	},
+	ext_runtime_version(spec_version: *mut u32, impl_version: *mut u32) -> u32 => {
+		match this.ext.runtime_version() {
+			Ok((spec, imp)) => {
+				this.memory.write_primitive(spec_version, spec)
+					.map_err(|_| UserError("Invalid attempt to write spec_version in ext_runtime_version"))?;
+				this.memory.write_primitive(impl_version, imp)
+					.map_err(|_| UserError("Invalid attempt to write impl_version in ext_runtime_version"))?;
+				Ok(1)
+			},
+			Err(()) => Ok(0),
+		}
+	},
+	ext_try_lock(name_data: *const u8, name_len: u32, ttl_millis: u64) -> u32 => {
+		let name = this.memory.get(name_data, name_len as usize)
+			.map_err(|_| UserError("OOB while ext_try_lock: wasm"))?;
+
+		let taken = this.ext.try_lock(&name, ::std::time::Duration::from_millis(ttl_millis))
+			.map_err(|_| UserError("Calling unavailable API ext_try_lock: wasm"))?;
+
+		Ok(if taken { 1 } else { 0 })
+	},
+	ext_unlock(name_data: *const u8, name_len: u32) => {
+		let name = this.memory.get(name_data, name_len as usize)
+			.map_err(|_| UserError("OOB while ext_unlock: wasm"))?;
+
+		this.ext.unlock(&name)
+			.map_err(|_| UserError("Calling unavailable API ext_unlock: wasm"))?;
+
+		Ok(())
+	},
+	ext_local_storage_set(key_data: *const u8, key_len: u32, value_data: *const u8, value_len: u32) => {
+		let key = this.memory.get(key_data, key_len as usize)
+			.map_err(|_| UserError("OOB while ext_local_storage_set: wasm"))?;
+		let value = this.memory.get(value_data, value_len as usize)
+			.map_err(|_| UserError("OOB while ext_local_storage_set: wasm"))?;
+
+		this.ext.local_storage_set(&key, &value)
+			.map_err(|_| UserError("Calling unavailable API ext_local_storage_set: wasm"))?;
+
+		Ok(())
+	},
+	ext_local_storage_get(key_data: *const u8, key_len: u32, written_out: *mut u32) -> *mut u8 => {
+		let key = this.memory.get(key_data, key_len as usize)
+			.map_err(|_| UserError("OOB while ext_local_storage_get: wasm"))?;
+
+		let maybe_value = this.ext.local_storage_get(&key)
+			.map_err(|_| UserError("Calling unavailable API ext_local_storage_get: wasm"))?;
+
+		if let Some(value) = maybe_value {
+			let offset = this.heap.allocate(value.len() as u32)? as u32;
+			this.memory.set(offset, &value)
+				.map_err(|_| UserError("Invalid attempt to set memory in ext_local_storage_get"))?;
+			this.memory.write_primitive(written_out, value.len() as u32)
+				.map_err(|_| UserError("Invalid attempt to write written_out in ext_local_storage_get"))?;
+			Ok(offset)
+		} else {
+			this.memory.write_primitive(written_out, u32::max_value())
+				.map_err(|_| UserError("Invalid attempt to write failed written_out in ext_local_storage_get"))?;
+			Ok(0)
+		}
+	},
+	ext_local_storage_compare_and_set(
+		key_data: *const u8,
+		key_len: u32,
+		old_value_data: *const u8,
+		old_value_len: u32,
+		new_value_data: *const u8,
+		new_value_len: u32
+	) -> u32 => {
+		let key = this.memory.get(key_data, key_len as usize)
+			.map_err(|_| UserError("OOB while ext_local_storage_compare_and_set: wasm"))?;
+		let old_value = if old_value_len == u32::max_value() {
+			None
+		} else {
+			Some(this.memory.get(old_value_data, old_value_len as usize)
+				.map_err(|_| UserError("OOB while ext_local_storage_compare_and_set: wasm"))?)
+		};
+		let new_value = this.memory.get(new_value_data, new_value_len as usize)
+			.map_err(|_| UserError("OOB while ext_local_storage_compare_and_set: wasm"))?;
+
+		let set = this.ext.local_storage_compare_and_set(&key, old_value.as_ref().map(|v| &v[..]), &new_value)
+			.map_err(|_| UserError("Calling unavailable API ext_local_storage_compare_and_set: wasm"))?;
+
+		Ok(if set { 1 } else { 0 })
+	},
+	ext_offchain_random_seed(seed_data: *mut u8) => {
+		let seed = this.ext.random_seed()
+			.map_err(|_| UserError("Calling unavailable API ext_offchain_random_seed: wasm"))?;
+		this.memory.set(seed_data, &seed)
+			.map_err(|_| UserError("Invalid attempt to set memory in ext_offchain_random_seed"))?;
+		Ok(())
+	},
+	ext_report_health(healthy: u32, detail_data: *const u8, detail_len: u32) => {
+		let detail = this.memory.get(detail_data, detail_len as usize)
+			.map_err(|_| UserError("OOB while ext_report_health: wasm"))?;
+		let detail = ::std::str::from_utf8(&detail)
+			.map_err(|_| UserError("Invalid utf8 detail in ext_report_health: wasm"))?;
+
+		this.ext.report_health(healthy != 0, detail)
+			.map_err(|_| UserError("Calling unavailable API ext_report_health: wasm"))?;
+
+		Ok(())
+	},
+	ext_next_attempt_delay(task_data: *const u8, task_len: u32, on_success: u32) -> u64 => {
+		let task = this.memory.get(task_data, task_len as usize)
+			.map_err(|_| UserError("OOB while ext_next_attempt_delay: wasm"))?;
+
+		let delay = this.ext.next_attempt_delay(&task, on_success != 0)
+			.map_err(|_| UserError("Calling unavailable API ext_next_attempt_delay: wasm"))?;
+
+		Ok(delay.as_millis() as u64)
+	},
+	ext_authority_keys(written_out: *mut u32) -> *mut u8 => {
+		let keys = this.ext.authority_keys()
+			.map_err(|_| UserError("Calling unavailable API ext_authority_keys: wasm"))?
+			.concat();
+
+		let offset = this.heap.allocate(keys.len() as u32)? as u32;
+		this.memory.set(offset, &keys)
+			.map_err(|_| UserError("Invalid attempt to set memory in ext_authority_keys"))?;
+		this.memory.write_primitive(written_out, keys.len() as u32)
+			.map_err(|_| UserError("Invalid attempt to write written_out in ext_authority_keys"))?;
+		Ok(offset)
+	},
 	ext_sandbox_instantiate(
 		dispatch_thunk_idx: usize,
 		wasm_ptr: *const u8,
@@ -14,10 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::{Arc, Mutex}, time::{Duration, SystemTime, UNIX_EPOCH}};
 use futures::{Stream, Future, sync::mpsc};
 use log::{info, debug, warn};
-use parity_codec::Decode;
+use parity_codec::{Decode, Encode};
 use primitives::OffchainExt;
 use runtime_primitives::{
 	generic::BlockId,
@@ -25,6 +25,42 @@ use runtime_primitives::{
 };
 use transaction_pool::txpool::{Pool, ChainApi};
 
+use crate::local_storage::LocalStorage;
+
+/// The most distinct metric names the workers may report; any further names are ignored.
+pub(crate) const MAX_METRICS: usize = 64;
+
+/// Whether the metric `name` may be recorded: it is already known or there is room for it.
+fn metric_has_room(metrics: &HashMap<String, u64>, name: &str) -> bool {
+	if metrics.contains_key(name) || metrics.len() < MAX_METRICS {
+		return true;
+	}
+	warn!("Ignoring offchain worker metric {:?}: at most {} metrics are kept.", name, MAX_METRICS);
+	false
+}
+
+/// The prefix of the keys the workers set in their local storage, which keeps them apart from
+/// the entries the node keeps there on their behalf.
+const WORKER_PREFIX: &[u8] = b"worker:";
+
+/// The key of the locks held by the workers in the local storage: the name of each along with
+/// the time it expires at, in milliseconds since the UNIX epoch.
+const LOCKS_KEY: &[u8] = b"locks";
+
+/// The prefix of the keys of the number of consecutive failures of each task of the workers in
+/// the local storage. Being outside `WORKER_PREFIX`, the workers cannot tamper with them.
+const BACKOFF_PREFIX: &[u8] = b"backoff:";
+
+/// The key under which a worker's `key` is kept in the local storage.
+fn worker_key(key: &[u8]) -> Vec<u8> {
+	WORKER_PREFIX.iter().chain(key).cloned().collect()
+}
+
+/// The current time in milliseconds since the UNIX epoch.
+fn unix_millis() -> u64 {
+	SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |now| now.as_millis() as u64)
+}
+
 /// A message between the offchain extension and the processing thread.
 enum ExtMessage {
 	SubmitExtrinsic(Vec<u8>),
@@ -33,11 +69,124 @@ enum ExtMessage {
 /// Asynchronous offchain API.
 ///
 /// NOTE this is done to prevent recursive calls into the runtime (which are not supported currently).
-pub(crate) struct AsyncApi(mpsc::UnboundedSender<ExtMessage>);
+pub(crate) struct AsyncApi {
+	sender: mpsc::UnboundedSender<ExtMessage>,
+	metrics: Arc<Mutex<HashMap<String, u64>>>,
+	runtime_version: Option<(u32, u32)>,
+	local_storage: Arc<dyn LocalStorage>,
+	authority_keys: Vec<[u8; 32]>,
+	health: Arc<Mutex<Option<(bool, String)>>>,
+}
+
+impl AsyncApi {
+	/// Updates the locks held by the workers with `f`, which is given those yet to expire and
+	/// returns whether to keep its change. Retries until no other worker updated them meanwhile.
+	fn update_locks(&self, mut f: impl FnMut(&mut Vec<(Vec<u8>, u64)>) -> bool) -> bool {
+		loop {
+			let current = self.local_storage.get(LOCKS_KEY);
+			let mut locks: Vec<(Vec<u8>, u64)> = current.as_ref()
+				.and_then(|locks| Decode::decode(&mut &locks[..]))
+				.unwrap_or_default();
+			let now = unix_millis();
+			locks.retain(|(_, expiry)| *expiry > now);
+			if !f(&mut locks) {
+				return false;
+			}
+			let current = current.as_ref().map(|locks| &locks[..]);
+			if self.local_storage.compare_and_set(LOCKS_KEY, current, &locks.encode()) {
+				return true;
+			}
+		}
+	}
+}
 
 impl OffchainExt for AsyncApi {
 	fn submit_extrinsic(&mut self, ext: Vec<u8>) {
-		let _ = self.0.unbounded_send(ExtMessage::SubmitExtrinsic(ext));
+		let _ = self.sender.unbounded_send(ExtMessage::SubmitExtrinsic(ext));
+	}
+
+	fn metric_increment(&mut self, name: &str, by: u64) {
+		if let Ok(mut metrics) = self.metrics.lock() {
+			if !metric_has_room(&metrics, name) {
+				return;
+			}
+			let value = metrics.entry(name.into()).or_insert(0);
+			*value = value.saturating_add(by);
+		}
+	}
+
+	fn metric_set(&mut self, name: &str, value: u64) {
+		if let Ok(mut metrics) = self.metrics.lock() {
+			if !metric_has_room(&metrics, name) {
+				return;
+			}
+			metrics.insert(name.into(), value);
+		}
+	}
+
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()> {
+		self.runtime_version.ok_or(())
+	}
+
+	fn try_lock(&mut self, name: &[u8], ttl: Duration) -> bool {
+		let expiry = unix_millis().saturating_add(ttl.as_millis() as u64);
+		self.update_locks(|locks| {
+			if locks.iter().any(|(held, _)| held[..] == name[..]) {
+				return false;
+			}
+			locks.push((name.to_vec(), expiry));
+			true
+		})
+	}
+
+	fn unlock(&mut self, name: &[u8]) {
+		self.update_locks(|locks| {
+			locks.retain(|(held, _)| held[..] != name[..]);
+			true
+		});
+	}
+
+	fn local_storage_set(&mut self, key: &[u8], value: &[u8]) {
+		self.local_storage.set(&worker_key(key), value)
+	}
+
+	fn local_storage_get(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		self.local_storage.get(&worker_key(key))
+	}
+
+	fn local_storage_compare_and_set(&mut self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+		self.local_storage.compare_and_set(&worker_key(key), old_value, new_value)
+	}
+
+	fn random_seed(&mut self) -> [u8; 32] {
+		rand::random()
+	}
+
+	fn authority_keys(&mut self) -> Vec<[u8; 32]> {
+		self.authority_keys.clone()
+	}
+
+	fn report_health(&mut self, healthy: bool, detail: &str) {
+		if let Ok(mut health) = self.health.lock() {
+			*health = Some((healthy, detail.into()));
+		}
+	}
+
+	fn next_attempt_delay(&mut self, task: &[u8], on_success: bool) -> Duration {
+		let key: Vec<u8> = BACKOFF_PREFIX.iter().chain(task).cloned().collect();
+		if on_success {
+			self.local_storage.set(&key, &0u32.encode());
+			return Duration::from_secs(0);
+		}
+
+		let failures = self.local_storage.get(&key)
+			.and_then(|value| u32::decode(&mut &value[..]))
+			.unwrap_or(0)
+			.saturating_add(1);
+		self.local_storage.set(&key, &failures.encode());
+		1u32.checked_shl(failures - 1)
+			.and_then(|factor| primitives::OFFCHAIN_BACKOFF_BASE.checked_mul(factor))
+			.map_or(primitives::OFFCHAIN_BACKOFF_MAX, |delay| delay.min(primitives::OFFCHAIN_BACKOFF_MAX))
 	}
 }
 
@@ -49,9 +198,18 @@ pub(crate) struct Api<A: ChainApi> {
 }
 
 impl<A: ChainApi> Api<A> {
+	/// Creates the API; the metrics reported by the worker are written to `metrics`. The worker
+	/// is told it runs in `runtime_version`, if known, keeps its data and its locks in
+	/// `local_storage`, is told the node holds `authority_keys` and reports its health to
+	/// `health`.
 	pub fn new(
 		transaction_pool: Arc<Pool<A>>,
 		at: BlockId<A::Block>,
+		metrics: Arc<Mutex<HashMap<String, u64>>>,
+		runtime_version: Option<(u32, u32)>,
+		local_storage: Arc<dyn LocalStorage>,
+		authority_keys: Vec<[u8; 32]>,
+		health: Arc<Mutex<Option<(bool, String)>>>,
 	) -> (AsyncApi, Self) {
 		let (tx, rx) = mpsc::unbounded();
 		let api = Self {
@@ -59,7 +217,15 @@ impl<A: ChainApi> Api<A> {
 			transaction_pool,
 			at,
 		};
-		(AsyncApi(tx), api)
+		let ext = AsyncApi {
+			sender: tx,
+			metrics,
+			runtime_version,
+			local_storage,
+			authority_keys,
+			health,
+		};
+		(ext, api)
 	}
 
 	/// Run a processing task for the API
@@ -92,3 +258,174 @@ impl<A: ChainApi> Api<A> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::local_storage::InMemoryLocalStorage;
+
+	fn async_api() -> AsyncApi {
+		AsyncApi {
+			sender: mpsc::unbounded().0,
+			metrics: Default::default(),
+			runtime_version: None,
+			local_storage: Arc::new(InMemoryLocalStorage::default()),
+			authority_keys: Vec::new(),
+			health: Default::default(),
+		}
+	}
+
+	#[test]
+	fn lock_cannot_be_taken_twice_until_unlocked() {
+		let mut api = async_api();
+		let ttl = Duration::from_secs(60);
+
+		assert!(api.try_lock(b"price", ttl));
+		assert!(!api.try_lock(b"price", ttl));
+		assert!(api.try_lock(b"other", ttl));
+
+		api.unlock(b"price");
+		assert!(api.try_lock(b"price", ttl));
+	}
+
+	#[test]
+	fn lock_is_shared_between_invocations() {
+		let mut first = async_api();
+		let mut second = AsyncApi { local_storage: first.local_storage.clone(), ..async_api() };
+
+		assert!(first.try_lock(b"price", Duration::from_secs(60)));
+		assert!(!second.try_lock(b"price", Duration::from_secs(60)));
+	}
+
+	#[test]
+	fn lock_expires_after_ttl() {
+		let mut api = async_api();
+
+		assert!(api.try_lock(b"price", Duration::from_millis(10)));
+		assert!(!api.try_lock(b"price", Duration::from_millis(10)));
+
+		std::thread::sleep(Duration::from_millis(20));
+		assert!(api.try_lock(b"price", Duration::from_millis(10)));
+	}
+
+	#[test]
+	fn expired_locks_are_pruned() {
+		let mut api = async_api();
+
+		assert!(api.try_lock(b"price", Duration::from_millis(10)));
+		std::thread::sleep(Duration::from_millis(20));
+		assert!(api.try_lock(b"other", Duration::from_secs(60)));
+
+		let locks: Vec<(Vec<u8>, u64)> = api.local_storage.get(LOCKS_KEY)
+			.and_then(|locks| Decode::decode(&mut &locks[..]))
+			.unwrap();
+		assert_eq!(locks.len(), 1);
+		assert_eq!(locks[0].0, b"other".to_vec());
+	}
+
+	#[test]
+	fn workers_cannot_overwrite_the_locks() {
+		let mut api = async_api();
+
+		assert!(api.try_lock(b"price", Duration::from_secs(60)));
+		api.local_storage_set(LOCKS_KEY, &Vec::<(Vec<u8>, u64)>::new().encode());
+		assert!(!api.try_lock(b"price", Duration::from_secs(60)));
+	}
+
+	#[test]
+	fn local_storage_is_kept_between_invocations() {
+		let mut first = async_api();
+		let mut second = AsyncApi { local_storage: first.local_storage.clone(), ..async_api() };
+
+		assert_eq!(first.local_storage_get(b"last"), None);
+		first.local_storage_set(b"last", b"1");
+		assert_eq!(second.local_storage_get(b"last"), Some(b"1".to_vec()));
+	}
+
+	#[test]
+	fn local_storage_compare_and_set_lets_one_racer_win() {
+		let mut first = async_api();
+		let mut second = AsyncApi { local_storage: first.local_storage.clone(), ..async_api() };
+
+		assert!(first.local_storage_compare_and_set(b"submitted", None, b"1"));
+		assert!(!second.local_storage_compare_and_set(b"submitted", None, b"1"));
+		assert!(second.local_storage_compare_and_set(b"submitted", Some(&b"1"[..]), b"2"));
+		assert_eq!(first.local_storage_get(b"submitted"), Some(b"2".to_vec()));
+	}
+
+	#[test]
+	fn random_seeds_differ() {
+		let mut api = async_api();
+		assert_ne!(api.random_seed(), api.random_seed());
+	}
+
+	#[test]
+	fn next_attempt_delay_grows_with_failures() {
+		let mut api = async_api();
+
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(1));
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(2));
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(4));
+		assert_eq!(api.next_attempt_delay(b"other", false), Duration::from_secs(1));
+		for _ in 0..40 {
+			api.next_attempt_delay(b"price", false);
+		}
+		assert_eq!(api.next_attempt_delay(b"price", false), primitives::OFFCHAIN_BACKOFF_MAX);
+	}
+
+	#[test]
+	fn next_attempt_delay_resets_on_success() {
+		let mut api = async_api();
+
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(1));
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(2));
+		assert_eq!(api.next_attempt_delay(b"price", true), Duration::from_secs(0));
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(1));
+	}
+
+	#[test]
+	fn workers_cannot_reset_their_backoff() {
+		let mut api = async_api();
+
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(1));
+		api.local_storage_set(b"backoff:price", &0u32.encode());
+		assert_eq!(api.next_attempt_delay(b"price", false), Duration::from_secs(2));
+	}
+
+	#[test]
+	fn authority_keys_are_those_given() {
+		let mut api = AsyncApi { authority_keys: vec![[1; 32], [2; 32]], ..async_api() };
+
+		assert_eq!(api.authority_keys(), vec![[1; 32], [2; 32]]);
+		assert_eq!(async_api().authority_keys(), Vec::<[u8; 32]>::new());
+	}
+
+	#[test]
+	fn only_the_latest_health_report_is_kept() {
+		let mut api = async_api();
+		let mut other = AsyncApi { health: api.health.clone(), ..async_api() };
+
+		api.report_health(false, "oracle endpoint down");
+		assert_eq!(*api.health.lock().unwrap(), Some((false, "oracle endpoint down".into())));
+
+		other.report_health(true, "");
+		assert_eq!(*api.health.lock().unwrap(), Some((true, "".into())));
+	}
+
+	#[test]
+	fn metric_names_are_bounded() {
+		let mut api = async_api();
+		for i in 0..MAX_METRICS {
+			api.metric_set(&format!("metric_{}", i), 1);
+		}
+
+		api.metric_set("one_too_many", 1);
+		api.metric_increment("one_too_many", 1);
+		api.metric_increment("metric_0", 1);
+
+		let metrics = api.metrics.lock().unwrap();
+		assert_eq!(metrics.len(), MAX_METRICS);
+		assert!(!metrics.contains_key("one_too_many"));
+		assert_eq!(metrics["metric_0"], 2);
+	}
+}
@@ -34,21 +34,24 @@
 #![warn(missing_docs)]
 
 use std::{
+	collections::HashMap,
 	marker::PhantomData,
-	sync::Arc,
+	sync::{Arc, Mutex},
 };
 
-use client::runtime_api::ApiExt;
+use client::{backend::AuxStore, runtime_api::{ApiExt, Core}};
 use log::{debug, warn};
 use primitives::ExecutionContext;
 use runtime_primitives::{
 	generic::BlockId,
 	traits::{self, ProvideRuntimeApi},
 };
+use substrate_telemetry::{telemetry, SUBSTRATE_INFO};
 use tokio::runtime::TaskExecutor;
 use transaction_pool::txpool::{Pool, ChainApi};
 
 mod api;
+mod local_storage;
 
 pub use offchain_primitives::OffchainWorkerApi;
 
@@ -57,23 +60,62 @@ pub use offchain_primitives::OffchainWorkerApi;
 pub struct OffchainWorkers<C, Block: traits::Block> {
 	client: Arc<C>,
 	executor: TaskExecutor,
+	/// The metrics reported by the workers so far, at most `api::MAX_METRICS` of them.
+	metrics: Arc<Mutex<HashMap<String, u64>>>,
+	/// The node-local storage of the workers and of the locks they hold, kept in the client's
+	/// auxiliary database so that it persists across restarts of the node.
+	local_storage: Arc<dyn local_storage::LocalStorage>,
+	/// The public keys of the authority keys held by the node.
+	authority_keys: Mutex<Vec<[u8; 32]>>,
+	/// The latest health reported by the workers, with a detail of what is degraded. Sent to
+	/// telemetry after every run.
+	health: Arc<Mutex<Option<(bool, String)>>>,
 	_block: PhantomData<Block>,
 }
 
-impl<C, Block: traits::Block> OffchainWorkers<C, Block> {
+impl<C, Block> OffchainWorkers<C, Block> where
+	Block: traits::Block,
+	C: AuxStore + Send + Sync + 'static,
+{
 	/// Creates new `OffchainWorkers`.
 	pub fn new(
 		client: Arc<C>,
 		executor: TaskExecutor,
 	) -> Self {
 		Self {
+			local_storage: Arc::new(local_storage::AuxLocalStorage::new(client.clone())),
 			client,
 			executor,
+			metrics: Default::default(),
+			authority_keys: Default::default(),
+			health: Default::default(),
 			_block: PhantomData,
 		}
 	}
 }
 
+impl<C, Block: traits::Block> OffchainWorkers<C, Block> {
+
+	/// Sets the public keys of the authority keys held by the node, which the workers are told
+	/// of from their next run on.
+	pub fn set_authority_keys(&self, keys: Vec<[u8; 32]>) {
+		if let Ok(mut authority_keys) = self.authority_keys.lock() {
+			*authority_keys = keys;
+		}
+	}
+
+	/// A snapshot of the metrics reported by the workers, by name.
+	pub fn metrics(&self) -> HashMap<String, u64> {
+		self.metrics.lock().map(|metrics| metrics.clone()).unwrap_or_default()
+	}
+
+	/// The latest health reported by the workers, if any: whether they are functioning and a
+	/// detail of what is degraded.
+	pub fn health(&self) -> Option<(bool, String)> {
+		self.health.lock().ok().and_then(|health| health.clone())
+	}
+}
+
 impl<C, Block> OffchainWorkers<C, Block> where
 	Block: traits::Block,
 	C: ProvideRuntimeApi,
@@ -93,12 +135,31 @@ impl<C, Block> OffchainWorkers<C, Block> where
 		debug!("Checking offchain workers at {:?}: {:?}", at, has_api);
 
 		if has_api.unwrap_or(false) {
-			let (api, runner) = api::Api::new(pool.clone(), at.clone());
+			let runtime_version = runtime.version(&at).ok()
+				.map(|version| (version.spec_version, version.impl_version));
+			let (api, runner) = api::Api::new(
+				pool.clone(),
+				at.clone(),
+				self.metrics.clone(),
+				runtime_version,
+				self.local_storage.clone(),
+				self.authority_keys.lock().map(|keys| keys.clone()).unwrap_or_default(),
+				self.health.clone(),
+			);
 			self.executor.spawn(runner.process());
 
 			debug!("Running offchain workers at {:?}", at);
 			let api = Box::new(api);
 			runtime.offchain_worker_with_context(&at, ExecutionContext::OffchainWorker(api), *number).unwrap();
+			telemetry!(SUBSTRATE_INFO; "offchain.metrics"; "number" => ?number, "metrics" => ?self.metrics());
+			if let Some((healthy, detail)) = self.health() {
+				if !healthy {
+					warn!("Offchain workers degraded at {:?}: {}", number, detail);
+				}
+				telemetry!(SUBSTRATE_INFO; "offchain.health";
+					"number" => ?number, "healthy" => healthy, "detail" => detail,
+				);
+			}
 		}
 	}
 }
@@ -0,0 +1,151 @@
+// Copyright 2019 Parity Technologies (UK) Ltd.
+// This file is part of Substrate.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Substrate.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Node-local storage of the offchain workers.
+
+use std::{fmt, sync::{Arc, Mutex}};
+use client::backend::AuxStore;
+use log::warn;
+
+/// The prefix of the workers' keys in the client's auxiliary storage, which keeps them apart
+/// from the data other components store there.
+const PREFIX: &[u8] = b"offchain_local_storage:";
+
+/// Storage the node keeps for its offchain workers between their runs.
+pub(crate) trait LocalStorage: Send + Sync + fmt::Debug {
+	/// Sets the value of `key`.
+	fn set(&self, key: &[u8], value: &[u8]);
+
+	/// Returns the value of `key`, if any.
+	fn get(&self, key: &[u8]) -> Option<Vec<u8>>;
+
+	/// Sets the value of `key` to `new_value`, provided it currently is `old_value` (`None`
+	/// meaning unset), atomically with respect to other workers. Returns whether it was set.
+	fn compare_and_set(&self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool;
+}
+
+/// Local storage kept in the client's auxiliary database, so that it survives restarts of the
+/// node.
+pub(crate) struct AuxLocalStorage<C> {
+	client: Arc<C>,
+	// serialises writes, which makes compare-and-set atomic.
+	lock: Mutex<()>,
+}
+
+impl<C> AuxLocalStorage<C> {
+	/// Creates the storage on top of `client`'s auxiliary database.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, lock: Mutex::new(()) }
+	}
+}
+
+impl<C> fmt::Debug for AuxLocalStorage<C> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("AuxLocalStorage").finish()
+	}
+}
+
+fn prefixed(key: &[u8]) -> Vec<u8> {
+	PREFIX.iter().chain(key).cloned().collect()
+}
+
+impl<C: AuxStore + Send + Sync> AuxLocalStorage<C> {
+	fn read(&self, key: &[u8]) -> Result<Option<Vec<u8>>, ()> {
+		self.client.get_aux(key)
+			.map_err(|e| warn!("Unable to read offchain local storage: {:?}", e))
+	}
+
+	fn write(&self, key: &[u8], value: &[u8]) -> bool {
+		self.client.insert_aux(&[(key, value)], &[])
+			.map_err(|e| warn!("Unable to write offchain local storage: {:?}", e))
+			.is_ok()
+	}
+}
+
+impl<C: AuxStore + Send + Sync> LocalStorage for AuxLocalStorage<C> {
+	fn set(&self, key: &[u8], value: &[u8]) {
+		let _guard = self.lock.lock();
+		self.write(&prefixed(key), value);
+	}
+
+	fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+		self.read(&prefixed(key)).ok().and_then(|value| value)
+	}
+
+	fn compare_and_set(&self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+		let _guard = match self.lock.lock() {
+			Ok(guard) => guard,
+			Err(_) => return false,
+		};
+		let key = prefixed(key);
+		match self.read(&key) {
+			Ok(ref current) if current.as_ref().map(|v| &v[..]) == old_value => self.write(&key, new_value),
+			_ => false,
+		}
+	}
+}
+
+/// Local storage kept in memory only, for tests.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub(crate) struct InMemoryLocalStorage(Mutex<std::collections::HashMap<Vec<u8>, Vec<u8>>>);
+
+#[cfg(test)]
+impl LocalStorage for InMemoryLocalStorage {
+	fn set(&self, key: &[u8], value: &[u8]) {
+		if let Ok(mut storage) = self.0.lock() {
+			storage.insert(key.to_vec(), value.to_vec());
+		}
+	}
+
+	fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+		self.0.lock().ok().and_then(|storage| storage.get(key).cloned())
+	}
+
+	fn compare_and_set(&self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+		let mut storage = match self.0.lock() {
+			Ok(storage) => storage,
+			Err(_) => return false,
+		};
+		if storage.get(key).map(|v| &v[..]) != old_value {
+			return false;
+		}
+		storage.insert(key.to_vec(), new_value.to_vec());
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn storage_is_kept_by_the_client() {
+		let client = Arc::new(test_client::new());
+		let first = AuxLocalStorage::new(client.clone());
+
+		first.set(b"last", b"1");
+		assert!(first.compare_and_set(b"submitted", None, b"1"));
+		assert!(!first.compare_and_set(b"submitted", None, b"2"));
+		drop(first);
+
+		// a storage opened afresh on the same database, as after a restart, sees the values.
+		let second = AuxLocalStorage::new(client.clone());
+		assert_eq!(second.get(b"last"), Some(b"1".to_vec()));
+		assert_eq!(second.get(b"submitted"), Some(b"1".to_vec()));
+		assert_eq!(client.get_aux(b"last").unwrap(), None);
+	}
+}
@@ -33,6 +33,7 @@ macro_rules! map {
 
 use rstd::prelude::*;
 use rstd::ops::Deref;
+use ::core::time::Duration;
 use parity_codec::{Encode, Decode};
 #[cfg(feature = "std")]
 use std::borrow::Cow;
@@ -90,6 +91,12 @@ pub enum ExecutionContext {
 	Other,
 }
 
+/// The delay `OffchainExt::next_attempt_delay` asks for after a first failure.
+pub const OFFCHAIN_BACKOFF_BASE: Duration = Duration::from_secs(1);
+
+/// The longest delay `OffchainExt::next_attempt_delay` asks for, however many failures.
+pub const OFFCHAIN_BACKOFF_MAX: Duration = Duration::from_secs(600);
+
 /// An extended externalities for offchain workers.
 pub trait OffchainExt {
 	/// Submits an extrinsics.
@@ -97,11 +104,106 @@ pub trait OffchainExt {
 	/// The extrinsic will either go to the pool (signed)
 	/// or to the next produced block (inherent).
 	fn submit_extrinsic(&mut self, extrinsic: Vec<u8>);
+
+	/// Increments the metric `name` by `by`.
+	fn metric_increment(&mut self, name: &str, by: u64);
+
+	/// Sets the metric `name` to `value`.
+	fn metric_set(&mut self, name: &str, value: u64);
+
+	/// Returns the `(spec_version, impl_version)` of the runtime the worker runs in.
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()>;
+
+	/// Tries to take the node-local lock `name`, which is held until released with `unlock` or
+	/// until `ttl` has passed, whichever comes first. Returns whether the lock was taken.
+	fn try_lock(&mut self, name: &[u8], ttl: Duration) -> bool;
+
+	/// Releases the node-local lock `name`.
+	fn unlock(&mut self, name: &[u8]);
+
+	/// Sets the value of `key` in the node-local storage, kept between runs of the worker.
+	fn local_storage_set(&mut self, key: &[u8], value: &[u8]);
+
+	/// Returns the value of `key` in the node-local storage, if any.
+	fn local_storage_get(&mut self, key: &[u8]) -> Option<Vec<u8>>;
+
+	/// Sets the value of `key` in the node-local storage to `new_value`, provided it currently
+	/// is `old_value` (`None` meaning unset). Returns whether the value was set.
+	///
+	/// The comparison and the update are atomic with respect to other workers.
+	fn local_storage_compare_and_set(&mut self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool;
+
+	/// Returns a fresh, cryptographically strong random seed.
+	///
+	/// The seed differs on every call and every node: it must never influence anything other
+	/// nodes have to reproduce, such as the content of a submitted extrinsic, only local
+	/// decisions like which peer to ask or how long to back off.
+	fn random_seed(&mut self) -> [u8; 32];
+
+	/// Returns the public keys of the authority keys held by the node, in no particular order.
+	fn authority_keys(&mut self) -> Vec<[u8; 32]>;
+
+	/// Reports whether the worker is functioning, with a human-readable `detail` of what is
+	/// degraded, if anything. Only the latest report is kept.
+	fn report_health(&mut self, healthy: bool, detail: &str);
+
+	/// Notes the outcome of an attempt at the recurring `task` and returns how long to wait
+	/// before the next one: nothing after a success and, after each consecutive failure, twice
+	/// as long as after the one before, from `OFFCHAIN_BACKOFF_BASE` up to `OFFCHAIN_BACKOFF_MAX`.
+	fn next_attempt_delay(&mut self, task: &[u8], on_success: bool) -> Duration;
 }
 impl<T: OffchainExt + ?Sized> OffchainExt for Box<T> {
 	fn submit_extrinsic(&mut self, ex: Vec<u8>) {
 		(&mut **self).submit_extrinsic(ex)
 	}
+
+	fn metric_increment(&mut self, name: &str, by: u64) {
+		(&mut **self).metric_increment(name, by)
+	}
+
+	fn metric_set(&mut self, name: &str, value: u64) {
+		(&mut **self).metric_set(name, value)
+	}
+
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()> {
+		(&mut **self).runtime_version()
+	}
+
+	fn try_lock(&mut self, name: &[u8], ttl: Duration) -> bool {
+		(&mut **self).try_lock(name, ttl)
+	}
+
+	fn unlock(&mut self, name: &[u8]) {
+		(&mut **self).unlock(name)
+	}
+
+	fn local_storage_set(&mut self, key: &[u8], value: &[u8]) {
+		(&mut **self).local_storage_set(key, value)
+	}
+
+	fn local_storage_get(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		(&mut **self).local_storage_get(key)
+	}
+
+	fn local_storage_compare_and_set(&mut self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+		(&mut **self).local_storage_compare_and_set(key, old_value, new_value)
+	}
+
+	fn random_seed(&mut self) -> [u8; 32] {
+		(&mut **self).random_seed()
+	}
+
+	fn authority_keys(&mut self) -> Vec<[u8; 32]> {
+		(&mut **self).authority_keys()
+	}
+
+	fn report_health(&mut self, healthy: bool, detail: &str) {
+		(&mut **self).report_health(healthy, detail)
+	}
+
+	fn next_attempt_delay(&mut self, task: &[u8], on_success: bool) -> Duration {
+		(&mut **self).next_attempt_delay(task, on_success)
+	}
 }
 
 /// Hex-serialized shim for `Vec<u8>`.
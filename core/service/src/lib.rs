@@ -75,7 +75,7 @@ pub struct Service<Components: components::Components> {
 	select_chain: Option<<Components as components::Components>::SelectChain>,
 	network: Option<Arc<components::NetworkService<Components::Factory>>>,
 	transaction_pool: Arc<TransactionPool<Components::TransactionPoolApi>>,
-	keystore: Keystore,
+	keystore: Arc<Keystore>,
 	exit: ::exit_future::Exit,
 	signal: Option<Signal>,
 	/// Configuration of this Service
@@ -147,6 +147,7 @@ impl<Components: components::Components> Service<Components> {
 				public_key
 			}
 		};
+		let keystore = Arc::new(keystore);
 
 		let (client, on_demand) = Components::build_client(&config, executor)?;
 		let select_chain = Components::build_select_chain(&mut config, client.clone())?;
@@ -210,10 +211,11 @@ impl<Components: components::Components> Service<Components> {
 			.then(|_| Ok(())));
 
 		let offchain_workers =  if config.offchain_worker {
-			Some(Arc::new(offchain::OffchainWorkers::new(
+			let offchain_workers = offchain::OffchainWorkers::new(
 				client.clone(),
 				task_executor.clone(),
-			)))
+			);
+			Some(Arc::new(offchain_workers))
 		} else {
 			None
 		};
@@ -224,6 +226,7 @@ impl<Components: components::Components> Service<Components> {
 			let txpool = Arc::downgrade(&transaction_pool);
 			let wclient = Arc::downgrade(&client);
 			let offchain = offchain_workers.as_ref().map(Arc::downgrade);
+			let wkeystore = Arc::downgrade(&keystore);
 
 			let events = client.import_notification_stream()
 				.for_each(move |notification| {
@@ -242,6 +245,13 @@ impl<Components: components::Components> Service<Components> {
 					}
 
 					if let (Some(txpool), Some(offchain)) = (txpool.upgrade(), offchain.as_ref().and_then(|o| o.upgrade())) {
+						// keys may have been added to the keystore since the last block.
+						if let Some(keystore) = wkeystore.upgrade() {
+							match keystore.contents() {
+								Ok(keys) => offchain.set_authority_keys(keys.into_iter().map(|key| key.0).collect()),
+								Err(e) => warn!("Unable to read the keystore for the offchain workers: {:?}", e),
+							}
+						}
 						Components::RuntimeServices::offchain_workers(
 							&number,
 							&offchain,
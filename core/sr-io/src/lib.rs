@@ -232,6 +232,52 @@ export_api! {
 		/// 1. scheduled to be included in the next produced block (inherent)
 		/// 2. added to the pool and propagated (transaction)
 		fn submit_extrinsic<T: codec::Encode>(data: &T);
+
+		/// Increment the offchain worker metric `name` by `by`.
+		fn metric_increment(name: &str, by: u64);
+
+		/// Set the offchain worker metric `name` to `value`.
+		fn metric_set(name: &str, value: u64);
+
+		/// Get the `(spec_version, impl_version)` of the runtime the offchain worker runs in, if
+		/// known.
+		fn runtime_version() -> Option<(u32, u32)>;
+
+		/// Try to take the node-local lock `name`, held until released with `unlock` or for at
+		/// most `ttl_millis` milliseconds. Returns whether the lock was taken.
+		fn try_lock(name: &[u8], ttl_millis: u64) -> bool;
+
+		/// Release the node-local lock `name`.
+		fn unlock(name: &[u8]);
+
+		/// Set the value of `key` in the offchain worker's node-local storage, which persists
+		/// between its runs.
+		fn local_storage_set(key: &[u8], value: &[u8]);
+
+		/// Get the value of `key` in the offchain worker's node-local storage, if any.
+		fn local_storage_get(key: &[u8]) -> Option<Vec<u8>>;
+
+		/// Set the value of `key` in the offchain worker's node-local storage to `new_value`,
+		/// provided it currently is `old_value` (`None` meaning unset), atomically with respect
+		/// to other workers. Returns whether the value was set.
+		fn local_storage_compare_and_set(key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool;
+
+		/// Get a fresh, non-deterministic random seed for the offchain worker.
+		///
+		/// It differs between nodes and runs, so it must only drive local decisions.
+		fn offchain_random_seed() -> [u8; 32];
+
+		/// Report whether the offchain worker is functioning, with a human-readable `detail` of
+		/// what is degraded, if anything. Only the latest report is kept.
+		fn report_health(healthy: bool, detail: &str);
+
+		/// Note the outcome of an attempt at the recurring `task` of the offchain worker and get
+		/// how long to wait before the next one, in milliseconds. The wait doubles with each
+		/// consecutive failure, up to a limit, and is reset by a success.
+		fn next_attempt_delay(task: &[u8], on_success: bool) -> u64;
+
+		/// Get the public keys of the authority keys held by the node, in no particular order.
+		fn authority_keys() -> Vec<[u8; 32]>;
 	}
 }
 
@@ -258,6 +258,89 @@ impl OffchainApi for () {
 			.expect("submit_extrinsic can be called only in offchain worker context")
 		).expect("submit_extrinsic cannot be called outside of an Externalities-provided environment.")
 	}
+
+	fn metric_increment(name: &str, by: u64) {
+		ext::with(|ext| ext
+			.metric_increment(name, by)
+			.expect("metric_increment can be called only in offchain worker context")
+		).expect("metric_increment cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn metric_set(name: &str, value: u64) {
+		ext::with(|ext| ext
+			.metric_set(name, value)
+			.expect("metric_set can be called only in offchain worker context")
+		).expect("metric_set cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn runtime_version() -> Option<(u32, u32)> {
+		ext::with(|ext| ext.runtime_version().ok())
+			.expect("runtime_version cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn try_lock(name: &[u8], ttl_millis: u64) -> bool {
+		ext::with(|ext| ext
+			.try_lock(name, std::time::Duration::from_millis(ttl_millis))
+			.expect("try_lock can be called only in offchain worker context")
+		).expect("try_lock cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn unlock(name: &[u8]) {
+		ext::with(|ext| ext
+			.unlock(name)
+			.expect("unlock can be called only in offchain worker context")
+		).expect("unlock cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn local_storage_set(key: &[u8], value: &[u8]) {
+		ext::with(|ext| ext
+			.local_storage_set(key, value)
+			.expect("local_storage_set can be called only in offchain worker context")
+		).expect("local_storage_set cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn local_storage_get(key: &[u8]) -> Option<Vec<u8>> {
+		ext::with(|ext| ext
+			.local_storage_get(key)
+			.expect("local_storage_get can be called only in offchain worker context")
+		).expect("local_storage_get cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn local_storage_compare_and_set(key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+		ext::with(|ext| ext
+			.local_storage_compare_and_set(key, old_value, new_value)
+			.expect("local_storage_compare_and_set can be called only in offchain worker context")
+		).expect("local_storage_compare_and_set cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn offchain_random_seed() -> [u8; 32] {
+		ext::with(|ext| ext
+			.random_seed()
+			.expect("offchain_random_seed can be called only in offchain worker context")
+		).expect("offchain_random_seed cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn report_health(healthy: bool, detail: &str) {
+		ext::with(|ext| ext
+			.report_health(healthy, detail)
+			.expect("report_health can be called only in offchain worker context")
+		).expect("report_health cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn next_attempt_delay(task: &[u8], on_success: bool) -> u64 {
+		ext::with(|ext| ext
+			.next_attempt_delay(task, on_success)
+			.expect("next_attempt_delay can be called only in offchain worker context")
+			.as_millis() as u64
+		).expect("next_attempt_delay cannot be called outside of an Externalities-provided environment.")
+	}
+
+	fn authority_keys() -> Vec<[u8; 32]> {
+		ext::with(|ext| ext
+			.authority_keys()
+			.expect("authority_keys can be called only in offchain worker context")
+		).expect("authority_keys cannot be called outside of an Externalities-provided environment.")
+	}
 }
 
 impl Api for () {}
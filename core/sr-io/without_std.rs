@@ -333,6 +333,67 @@ pub mod ext {
 
 		/// Submit extrinsic.
 		fn ext_submit_extrinsic(data: *const u8, len: u32);
+		/// Increment an offchain worker metric.
+		fn ext_metric_increment(name_data: *const u8, name_len: u32, by: u64);
+		/// Set an offchain worker metric.
+		fn ext_metric_set(name_data: *const u8, name_len: u32, value: u64);
+		/// Get the version of the runtime an offchain worker runs in.
+		///
+		/// # Returns
+		///
+		/// - `1` if the version is known, written to `spec_version` and `impl_version`.
+		/// - `0` otherwise.
+		fn ext_runtime_version(spec_version: *mut u32, impl_version: *mut u32) -> u32;
+		/// Try to take a node-local lock for at most `ttl_millis` milliseconds.
+		///
+		/// # Returns
+		///
+		/// - `1` if the lock was taken.
+		/// - `0` if it is held already.
+		fn ext_try_lock(name_data: *const u8, name_len: u32, ttl_millis: u64) -> u32;
+		/// Release a node-local lock.
+		fn ext_unlock(name_data: *const u8, name_len: u32);
+		/// Set a value in the offchain worker's node-local storage.
+		fn ext_local_storage_set(key_data: *const u8, key_len: u32, value_data: *const u8, value_len: u32);
+		/// Gets a value from the offchain worker's node-local storage.
+		///
+		/// The host allocates the memory for storing the value.
+		///
+		/// # Returns
+		///
+		/// - `0` if no value exists to the given key. `written_out` is set to `u32::max_value()`.
+		///
+		/// - Otherwise, pointer to the value in memory. `written_out` contains the length of the value.
+		fn ext_local_storage_get(key_data: *const u8, key_len: u32, written_out: *mut u32) -> *mut u8;
+		/// Set a value in the offchain worker's node-local storage if it currently is the given one.
+		///
+		/// An `old_value_len` of `u32::max_value()` stands for an unset value.
+		///
+		/// # Returns
+		///
+		/// - `1` if the value was set.
+		/// - `0` otherwise.
+		fn ext_local_storage_compare_and_set(
+			key_data: *const u8,
+			key_len: u32,
+			old_value_data: *const u8,
+			old_value_len: u32,
+			new_value_data: *const u8,
+			new_value_len: u32
+		) -> u32;
+		/// Write a fresh random seed of 32 bytes to `seed_data`.
+		fn ext_offchain_random_seed(seed_data: *mut u8);
+		/// Report the health of the offchain worker, `healthy` being `0` or `1`, with a UTF-8
+		/// encoded `detail`.
+		fn ext_report_health(healthy: u32, detail_data: *const u8, detail_len: u32);
+		/// Note the outcome of an attempt at `task`, `on_success` being `0` or `1`, and get the
+		/// delay before the next attempt in milliseconds.
+		fn ext_next_attempt_delay(task_data: *const u8, task_len: u32, on_success: u32) -> u64;
+		/// Get the public keys of the authority keys held by the node.
+		///
+		/// The host allocates the memory for storing the keys, concatenated, 32 bytes each.
+		/// `written_out` contains the length of the keys in bytes.
+		fn ext_authority_keys(written_out: *mut u32) -> *mut u8;
 	}
 }
 
@@ -630,6 +691,120 @@ impl OffchainApi for () {
 			ext_submit_extrinsic.get()(encoded_data.as_ptr(), encoded_data.len() as u32)
 		}
 	}
+
+	fn metric_increment(name: &str, by: u64) {
+		unsafe {
+			ext_metric_increment.get()(name.as_ptr(), name.len() as u32, by)
+		}
+	}
+
+	fn metric_set(name: &str, value: u64) {
+		unsafe {
+			ext_metric_set.get()(name.as_ptr(), name.len() as u32, value)
+		}
+	}
+
+	fn runtime_version() -> Option<(u32, u32)> {
+		let mut spec_version = 0;
+		let mut impl_version = 0;
+		let is_known = unsafe {
+			ext_runtime_version.get()(&mut spec_version, &mut impl_version)
+		};
+
+		if is_known != 0 {
+			Some((spec_version, impl_version))
+		} else {
+			None
+		}
+	}
+
+	fn try_lock(name: &[u8], ttl_millis: u64) -> bool {
+		unsafe {
+			ext_try_lock.get()(name.as_ptr(), name.len() as u32, ttl_millis) != 0
+		}
+	}
+
+	fn unlock(name: &[u8]) {
+		unsafe {
+			ext_unlock.get()(name.as_ptr(), name.len() as u32)
+		}
+	}
+
+	fn local_storage_set(key: &[u8], value: &[u8]) {
+		unsafe {
+			ext_local_storage_set.get()(
+				key.as_ptr(), key.len() as u32,
+				value.as_ptr(), value.len() as u32
+			);
+		}
+	}
+
+	fn local_storage_get(key: &[u8]) -> Option<Vec<u8>> {
+		let mut length: u32 = 0;
+		unsafe {
+			let ptr = ext_local_storage_get.get()(key.as_ptr(), key.len() as u32, &mut length);
+			if length == u32::max_value() {
+				None
+			} else {
+				// Invariants required by Vec::from_raw_parts are not formally fulfilled.
+				// We don't allocate via String/Vec<T>, but use a custom allocator instead.
+				// See #300 for more details.
+				Some(<Vec<u8>>::from_raw_parts(ptr, length as usize, length as usize))
+			}
+		}
+	}
+
+	fn local_storage_compare_and_set(key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+		let (old_value_data, old_value_len) = match old_value {
+			Some(old_value) => (old_value.as_ptr(), old_value.len() as u32),
+			None => (0 as *const u8, u32::max_value()),
+		};
+		unsafe {
+			ext_local_storage_compare_and_set.get()(
+				key.as_ptr(), key.len() as u32,
+				old_value_data, old_value_len,
+				new_value.as_ptr(), new_value.len() as u32
+			) != 0
+		}
+	}
+
+	fn offchain_random_seed() -> [u8; 32] {
+		let mut result: [u8; 32] = Default::default();
+		unsafe {
+			ext_offchain_random_seed.get()(result.as_mut_ptr());
+		}
+		result
+	}
+
+	fn report_health(healthy: bool, detail: &str) {
+		unsafe {
+			ext_report_health.get()(healthy as u32, detail.as_ptr(), detail.len() as u32);
+		}
+	}
+
+	fn next_attempt_delay(task: &[u8], on_success: bool) -> u64 {
+		unsafe {
+			ext_next_attempt_delay.get()(task.as_ptr(), task.len() as u32, on_success as u32)
+		}
+	}
+
+	fn authority_keys() -> Vec<[u8; 32]> {
+		let mut length: u32 = 0;
+		let keys = unsafe {
+			let ptr = ext_authority_keys.get()(&mut length);
+			// Invariants required by Vec::from_raw_parts are not formally fulfilled.
+			// We don't allocate via String/Vec<T>, but use a custom allocator instead.
+			// See #300 for more details.
+			<Vec<u8>>::from_raw_parts(ptr, length as usize, length as usize)
+		};
+		keys.chunks(32)
+			.map(|chunk| {
+				let mut key = [0u8; 32];
+				key.copy_from_slice(chunk);
+				key
+			})
+			.collect()
+	}
 }
 
 impl Api for () {}
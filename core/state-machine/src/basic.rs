@@ -159,6 +159,71 @@ impl<H: Hasher> Externalities<H> for BasicExternalities where H::Out: Ord {
 		warn!("Call to submit_extrinsic without offchain externalities set.");
 		Err(())
 	}
+
+	fn metric_increment(&mut self, _name: &str, _by: u64) -> Result<(), ()> {
+		warn!("Call to metric_increment without offchain externalities set.");
+		Err(())
+	}
+
+	fn metric_set(&mut self, _name: &str, _value: u64) -> Result<(), ()> {
+		warn!("Call to metric_set without offchain externalities set.");
+		Err(())
+	}
+
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()> {
+		warn!("Call to runtime_version without offchain externalities set.");
+		Err(())
+	}
+
+	fn try_lock(&mut self, _name: &[u8], _ttl: std::time::Duration) -> Result<bool, ()> {
+		warn!("Call to try_lock without offchain externalities set.");
+		Err(())
+	}
+
+	fn unlock(&mut self, _name: &[u8]) -> Result<(), ()> {
+		warn!("Call to unlock without offchain externalities set.");
+		Err(())
+	}
+
+	fn local_storage_set(&mut self, _key: &[u8], _value: &[u8]) -> Result<(), ()> {
+		warn!("Call to local_storage_set without offchain externalities set.");
+		Err(())
+	}
+
+	fn local_storage_get(&mut self, _key: &[u8]) -> Result<Option<Vec<u8>>, ()> {
+		warn!("Call to local_storage_get without offchain externalities set.");
+		Err(())
+	}
+
+	fn local_storage_compare_and_set(
+		&mut self,
+		_key: &[u8],
+		_old_value: Option<&[u8]>,
+		_new_value: &[u8],
+	) -> Result<bool, ()> {
+		warn!("Call to local_storage_compare_and_set without offchain externalities set.");
+		Err(())
+	}
+
+	fn random_seed(&mut self) -> Result<[u8; 32], ()> {
+		warn!("Call to random_seed without offchain externalities set.");
+		Err(())
+	}
+
+	fn authority_keys(&mut self) -> Result<Vec<[u8; 32]>, ()> {
+		warn!("Call to authority_keys without offchain externalities set.");
+		Err(())
+	}
+
+	fn report_health(&mut self, _healthy: bool, _detail: &str) -> Result<(), ()> {
+		warn!("Call to report_health without offchain externalities set.");
+		Err(())
+	}
+
+	fn next_attempt_delay(&mut self, _task: &[u8], _on_success: bool) -> Result<std::time::Duration, ()> {
+		warn!("Call to next_attempt_delay without offchain externalities set.");
+		Err(())
+	}
 }
 
 #[cfg(test)]
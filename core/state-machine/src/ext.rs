@@ -352,6 +352,136 @@ where
 			Err(())
 		}
 	}
+
+	fn metric_increment(&mut self, name: &str, by: u64) -> Result<(), ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			ext.metric_increment(name, by);
+			Ok(())
+		} else {
+			warn!("Call to metric_increment without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn metric_set(&mut self, name: &str, value: u64) -> Result<(), ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			ext.metric_set(name, value);
+			Ok(())
+		} else {
+			warn!("Call to metric_set without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			ext.runtime_version()
+		} else {
+			warn!("Call to runtime_version without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn try_lock(&mut self, name: &[u8], ttl: std::time::Duration) -> Result<bool, ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			Ok(ext.try_lock(name, ttl))
+		} else {
+			warn!("Call to try_lock without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn unlock(&mut self, name: &[u8]) -> Result<(), ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			ext.unlock(name);
+			Ok(())
+		} else {
+			warn!("Call to unlock without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn local_storage_set(&mut self, key: &[u8], value: &[u8]) -> Result<(), ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			ext.local_storage_set(key, value);
+			Ok(())
+		} else {
+			warn!("Call to local_storage_set without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn local_storage_get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			Ok(ext.local_storage_get(key))
+		} else {
+			warn!("Call to local_storage_get without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn local_storage_compare_and_set(
+		&mut self,
+		key: &[u8],
+		old_value: Option<&[u8]>,
+		new_value: &[u8],
+	) -> Result<bool, ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			Ok(ext.local_storage_compare_and_set(key, old_value, new_value))
+		} else {
+			warn!("Call to local_storage_compare_and_set without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn random_seed(&mut self) -> Result<[u8; 32], ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			Ok(ext.random_seed())
+		} else {
+			warn!("Call to random_seed without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn authority_keys(&mut self) -> Result<Vec<[u8; 32]>, ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			Ok(ext.authority_keys())
+		} else {
+			warn!("Call to authority_keys without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn report_health(&mut self, healthy: bool, detail: &str) -> Result<(), ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			ext.report_health(healthy, detail);
+			Ok(())
+		} else {
+			warn!("Call to report_health without offchain externalities set.");
+			Err(())
+		}
+	}
+
+	fn next_attempt_delay(&mut self, task: &[u8], on_success: bool) -> Result<std::time::Duration, ()> {
+		let _guard = panic_handler::AbortGuard::new(true);
+		if let Some(ext) = self.offchain_externalities.as_mut() {
+			Ok(ext.next_attempt_delay(task, on_success))
+		} else {
+			warn!("Call to next_attempt_delay without offchain externalities set.");
+			Err(())
+		}
+	}
 }
 
 #[cfg(test)]
@@ -428,4 +558,239 @@ mod tests {
 		assert_eq!(ext.storage_changes_root(Default::default()).unwrap(),
 			Some(hex!("bcf494e41e29a15c9ae5caa053fe3cb8b446ee3e02a254efbdec7a19235b76e4").into()));
 	}
+
+	#[derive(Default)]
+	struct RecordingOffchainExt {
+		extrinsics: Vec<Vec<u8>>,
+		metrics: std::collections::HashMap<String, u64>,
+		version: Option<(u32, u32)>,
+		locks: Vec<Vec<u8>>,
+		local_storage: std::collections::HashMap<Vec<u8>, Vec<u8>>,
+		seeds: u8,
+		authority_keys: Vec<[u8; 32]>,
+		health: Option<(bool, String)>,
+		attempts: Vec<(Vec<u8>, bool)>,
+	}
+
+	impl OffchainExt for RecordingOffchainExt {
+		fn submit_extrinsic(&mut self, extrinsic: Vec<u8>) {
+			self.extrinsics.push(extrinsic);
+		}
+
+		fn metric_increment(&mut self, name: &str, by: u64) {
+			*self.metrics.entry(name.into()).or_insert(0) += by;
+		}
+
+		fn metric_set(&mut self, name: &str, value: u64) {
+			self.metrics.insert(name.into(), value);
+		}
+
+		fn runtime_version(&mut self) -> Result<(u32, u32), ()> {
+			self.version.ok_or(())
+		}
+
+		fn try_lock(&mut self, name: &[u8], _ttl: std::time::Duration) -> bool {
+			if self.locks.iter().any(|l| l == name) {
+				return false;
+			}
+			self.locks.push(name.to_vec());
+			true
+		}
+
+		fn unlock(&mut self, name: &[u8]) {
+			self.locks.retain(|l| l != name);
+		}
+
+		fn local_storage_set(&mut self, key: &[u8], value: &[u8]) {
+			self.local_storage.insert(key.to_vec(), value.to_vec());
+		}
+
+		fn local_storage_get(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+			self.local_storage.get(key).cloned()
+		}
+
+		fn local_storage_compare_and_set(&mut self, key: &[u8], old_value: Option<&[u8]>, new_value: &[u8]) -> bool {
+			if self.local_storage.get(key).map(|v| &v[..]) != old_value {
+				return false;
+			}
+			self.local_storage.insert(key.to_vec(), new_value.to_vec());
+			true
+		}
+
+		fn random_seed(&mut self) -> [u8; 32] {
+			self.seeds += 1;
+			[self.seeds; 32]
+		}
+
+		fn authority_keys(&mut self) -> Vec<[u8; 32]> {
+			self.authority_keys.clone()
+		}
+
+		fn report_health(&mut self, healthy: bool, detail: &str) {
+			self.health = Some((healthy, detail.into()));
+		}
+
+		fn next_attempt_delay(&mut self, task: &[u8], on_success: bool) -> std::time::Duration {
+			self.attempts.push((task.to_vec(), on_success));
+			std::time::Duration::from_secs(if on_success { 0 } else { 1 })
+		}
+	}
+
+	type OffchainTestExt<'a> =
+		Ext<'a, Blake2Hasher, u64, TestBackend, TestChangesTrieStorage, Box<RecordingOffchainExt>>;
+
+	/// Runs `f` on externalities over empty storage which pass offchain calls to `offchain`.
+	fn with_offchain_ext<R>(
+		offchain: &mut Box<RecordingOffchainExt>,
+		f: impl FnOnce(&mut OffchainTestExt<'_>) -> R,
+	) -> R {
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut ext = Ext::new(&mut overlay, &backend, None, Some(offchain));
+		f(&mut ext)
+	}
+
+	#[test]
+	fn metrics_are_passed_to_offchain_ext() {
+		let mut offchain = Box::new(RecordingOffchainExt::default());
+		with_offchain_ext(&mut offchain, |ext| {
+			assert_eq!(ext.metric_increment("requests", 1), Ok(()));
+			assert_eq!(ext.metric_increment("requests", 2), Ok(()));
+			assert_eq!(ext.metric_increment("failures", 1), Ok(()));
+			assert_eq!(ext.metric_set("queue_length", 7), Ok(()));
+			assert_eq!(ext.metric_set("queue_length", 5), Ok(()));
+		});
+		assert_eq!(offchain.metrics.len(), 3);
+		assert_eq!(offchain.metrics["requests"], 3);
+		assert_eq!(offchain.metrics["failures"], 1);
+		assert_eq!(offchain.metrics["queue_length"], 5);
+	}
+
+	#[test]
+	fn runtime_version_gates_offchain_worker() {
+		// A worker which only submits its extrinsic on a runtime it knows about.
+		fn worker<E: Externalities<Blake2Hasher>>(ext: &mut E) {
+			match ext.runtime_version() {
+				Ok((spec_version, _)) if spec_version >= 2 => {
+					let _ = ext.submit_extrinsic(vec![spec_version as u8]);
+				},
+				_ => {},
+			}
+		}
+
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		for (version, expected) in vec![
+			(Some((1, 5)), vec![]),
+			(Some((2, 0)), vec![vec![2]]),
+			(None, vec![]),
+		] {
+			let mut offchain = Box::new(RecordingOffchainExt { version, ..Default::default() });
+			with_offchain_ext(&mut offchain, |ext| {
+				assert_eq!(ext.runtime_version(), version.ok_or(()));
+				worker(ext);
+			});
+			assert_eq!(offchain.extrinsics, expected);
+		}
+
+		let mut ext = TestExt::new(&mut overlay, &backend, None, None);
+		assert_eq!(ext.runtime_version(), Err(()));
+	}
+
+	#[test]
+	fn local_storage_is_passed_to_offchain_ext() {
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut offchain = Box::new(RecordingOffchainExt::default());
+		with_offchain_ext(&mut offchain, |ext| {
+			assert_eq!(ext.local_storage_get(b"last"), Ok(None));
+			assert_eq!(ext.local_storage_compare_and_set(b"last", None, b"1"), Ok(true));
+			assert_eq!(ext.local_storage_compare_and_set(b"last", None, b"2"), Ok(false));
+			assert_eq!(ext.local_storage_compare_and_set(b"last", Some(&b"1"[..]), b"2"), Ok(true));
+			assert_eq!(ext.local_storage_set(b"other", b"3"), Ok(()));
+			assert_eq!(ext.local_storage_get(b"last"), Ok(Some(b"2".to_vec())));
+		});
+		assert_eq!(offchain.local_storage.len(), 2);
+
+		let mut ext = TestExt::new(&mut overlay, &backend, None, None);
+		assert_eq!(ext.local_storage_get(b"last"), Err(()));
+	}
+
+	#[test]
+	fn random_seed_is_passed_from_offchain_ext() {
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut offchain = Box::new(RecordingOffchainExt::default());
+		with_offchain_ext(&mut offchain, |ext| {
+			let first = ext.random_seed().unwrap();
+			let second = ext.random_seed().unwrap();
+			assert_ne!(first, second);
+		});
+
+		let mut ext = TestExt::new(&mut overlay, &backend, None, None);
+		assert_eq!(ext.random_seed(), Err(()));
+	}
+
+	#[test]
+	fn worker_picks_a_local_authority_key() {
+		// signs as the first of the current authorities whose key the node holds.
+		fn signer<E: Externalities<Blake2Hasher>>(ext: &mut E, authorities: &[[u8; 32]]) -> Option<[u8; 32]> {
+			let local = ext.authority_keys().ok()?;
+			authorities.iter().find(|a| local.contains(a)).cloned()
+		}
+
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut offchain = Box::new(RecordingOffchainExt {
+			authority_keys: vec![[1; 32], [2; 32]],
+			..Default::default()
+		});
+		with_offchain_ext(&mut offchain, |ext| {
+			assert_eq!(ext.authority_keys(), Ok(vec![[1; 32], [2; 32]]));
+			assert_eq!(signer(ext, &[[3; 32], [2; 32]]), Some([2; 32]));
+			assert_eq!(signer(ext, &[[3; 32]]), None);
+		});
+
+		let mut ext = TestExt::new(&mut overlay, &backend, None, None);
+		assert_eq!(signer(&mut ext, &[[1; 32]]), None);
+	}
+
+	#[test]
+	fn worker_reports_its_latest_health() {
+		// a worker which depends on an oracle being reachable.
+		fn worker<E: Externalities<Blake2Hasher>>(ext: &mut E, oracle_up: bool) {
+			let _ = match oracle_up {
+				true => ext.report_health(true, ""),
+				false => ext.report_health(false, "oracle endpoint down"),
+			};
+		}
+
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut offchain = Box::new(RecordingOffchainExt::default());
+		with_offchain_ext(&mut offchain, |ext| {
+			worker(ext, true);
+			assert_eq!(ext.offchain_externalities.as_ref().unwrap().health, Some((true, "".into())));
+			worker(ext, false);
+		});
+		assert_eq!(offchain.health, Some((false, "oracle endpoint down".into())));
+
+		let mut ext = TestExt::new(&mut overlay, &backend, None, None);
+		assert_eq!(ext.report_health(false, "oracle endpoint down"), Err(()));
+	}
+
+	#[test]
+	fn attempt_outcomes_are_passed_to_offchain_ext() {
+		let mut overlay = OverlayedChanges::default();
+		let backend = TestBackend::default();
+		let mut offchain = Box::new(RecordingOffchainExt::default());
+		with_offchain_ext(&mut offchain, |ext| {
+			assert_eq!(ext.next_attempt_delay(b"price", false), Ok(std::time::Duration::from_secs(1)));
+			assert_eq!(ext.next_attempt_delay(b"price", true), Ok(std::time::Duration::from_secs(0)));
+		});
+		assert_eq!(offchain.attempts, vec![(b"price".to_vec(), false), (b"price".to_vec(), true)]);
+
+		let mut ext = TestExt::new(&mut overlay, &backend, None, None);
+		assert_eq!(ext.next_attempt_delay(b"price", false), Err(()));
+	}
 }
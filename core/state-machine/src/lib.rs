@@ -18,7 +18,7 @@
 
 #![warn(missing_docs)]
 
-use std::{fmt, panic::UnwindSafe, result, marker::PhantomData};
+use std::{fmt, panic::UnwindSafe, result, marker::PhantomData, time::Duration};
 use std::borrow::Cow;
 use log::warn;
 use hash_db::Hasher;
@@ -225,6 +225,76 @@ pub trait Externalities<H: Hasher> {
 	///
 	/// Returns an error in case the API is not available.
 	fn submit_extrinsic(&mut self, extrinsic: Vec<u8>) -> Result<(), ()>;
+
+	/// Increment the offchain worker metric `name` by `by`.
+	///
+	/// Returns an error in case the API is not available.
+	fn metric_increment(&mut self, name: &str, by: u64) -> Result<(), ()>;
+
+	/// Set the offchain worker metric `name` to `value`.
+	///
+	/// Returns an error in case the API is not available.
+	fn metric_set(&mut self, name: &str, value: u64) -> Result<(), ()>;
+
+	/// Get the `(spec_version, impl_version)` of the runtime the offchain worker runs in.
+	///
+	/// Returns an error in case the API is not available.
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()>;
+
+	/// Try to take the node-local lock `name` for at most `ttl`.
+	///
+	/// Returns an error in case the API is not available.
+	fn try_lock(&mut self, name: &[u8], ttl: Duration) -> Result<bool, ()>;
+
+	/// Release the node-local lock `name`.
+	///
+	/// Returns an error in case the API is not available.
+	fn unlock(&mut self, name: &[u8]) -> Result<(), ()>;
+
+	/// Set the value of `key` in the offchain worker's node-local storage.
+	///
+	/// Returns an error in case the API is not available.
+	fn local_storage_set(&mut self, key: &[u8], value: &[u8]) -> Result<(), ()>;
+
+	/// Get the value of `key` in the offchain worker's node-local storage.
+	///
+	/// Returns an error in case the API is not available.
+	fn local_storage_get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, ()>;
+
+	/// Atomically set the value of `key` in the offchain worker's node-local storage to
+	/// `new_value` if it is currently `old_value`, returning whether it was set.
+	///
+	/// Returns an error in case the API is not available.
+	fn local_storage_compare_and_set(
+		&mut self,
+		key: &[u8],
+		old_value: Option<&[u8]>,
+		new_value: &[u8],
+	) -> Result<bool, ()>;
+
+	/// Get a fresh random seed for the offchain worker. Unlike the on-chain random seed this is
+	/// non-deterministic, so must only drive local decisions, never state other nodes reproduce.
+	///
+	/// Returns an error in case the API is not available.
+	fn random_seed(&mut self) -> Result<[u8; 32], ()>;
+
+	/// Get the public keys of the authority keys held by the node, so that an offchain worker
+	/// knows which authority it may sign as.
+	///
+	/// Returns an error in case the API is not available.
+	fn authority_keys(&mut self) -> Result<Vec<[u8; 32]>, ()>;
+
+	/// Report the health of the offchain worker to the node, with a `detail` of what is
+	/// degraded, e.g. an unreachable oracle endpoint.
+	///
+	/// Returns an error in case the API is not available.
+	fn report_health(&mut self, healthy: bool, detail: &str) -> Result<(), ()>;
+
+	/// Note the outcome of an attempt at the recurring `task` of the offchain worker and get
+	/// how long to wait before the next one, which grows with each consecutive failure.
+	///
+	/// Returns an error in case the API is not available.
+	fn next_attempt_delay(&mut self, task: &[u8], on_success: bool) -> Result<Duration, ()>;
 }
 
 /// An implementation of offchain extensions that should never be triggered.
@@ -239,6 +309,35 @@ impl NeverOffchainExt {
 
 impl OffchainExt for NeverOffchainExt {
 	fn submit_extrinsic(&mut self, _extrinsic: Vec<u8>) { unreachable!() }
+
+	fn metric_increment(&mut self, _name: &str, _by: u64) { unreachable!() }
+
+	fn metric_set(&mut self, _name: &str, _value: u64) { unreachable!() }
+
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()> { unreachable!() }
+
+	fn try_lock(&mut self, _name: &[u8], _ttl: Duration) -> bool { unreachable!() }
+
+	fn unlock(&mut self, _name: &[u8]) { unreachable!() }
+
+	fn local_storage_set(&mut self, _key: &[u8], _value: &[u8]) { unreachable!() }
+
+	fn local_storage_get(&mut self, _key: &[u8]) -> Option<Vec<u8>> { unreachable!() }
+
+	fn local_storage_compare_and_set(
+		&mut self,
+		_key: &[u8],
+		_old_value: Option<&[u8]>,
+		_new_value: &[u8],
+	) -> bool { unreachable!() }
+
+	fn random_seed(&mut self) -> [u8; 32] { unreachable!() }
+
+	fn authority_keys(&mut self) -> Vec<[u8; 32]> { unreachable!() }
+
+	fn report_health(&mut self, _healthy: bool, _detail: &str) { unreachable!() }
+
+	fn next_attempt_delay(&mut self, _task: &[u8], _on_success: bool) -> Duration { unreachable!() }
 }
 
 /// Code execution engine.
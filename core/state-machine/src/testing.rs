@@ -229,6 +229,59 @@ impl<H, N> Externalities<H> for TestExternalities<H, N>
 	fn submit_extrinsic(&mut self, _extrinsic: Vec<u8>) -> Result<(), ()> {
 		unimplemented!()
 	}
+
+	fn metric_increment(&mut self, _name: &str, _by: u64) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn metric_set(&mut self, _name: &str, _value: u64) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn runtime_version(&mut self) -> Result<(u32, u32), ()> {
+		Err(())
+	}
+
+	fn try_lock(&mut self, _name: &[u8], _ttl: std::time::Duration) -> Result<bool, ()> {
+		Err(())
+	}
+
+	fn unlock(&mut self, _name: &[u8]) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn local_storage_set(&mut self, _key: &[u8], _value: &[u8]) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn local_storage_get(&mut self, _key: &[u8]) -> Result<Option<Vec<u8>>, ()> {
+		Err(())
+	}
+
+	fn local_storage_compare_and_set(
+		&mut self,
+		_key: &[u8],
+		_old_value: Option<&[u8]>,
+		_new_value: &[u8],
+	) -> Result<bool, ()> {
+		Err(())
+	}
+
+	fn random_seed(&mut self) -> Result<[u8; 32], ()> {
+		Err(())
+	}
+
+	fn authority_keys(&mut self) -> Result<Vec<[u8; 32]>, ()> {
+		Err(())
+	}
+
+	fn report_health(&mut self, _healthy: bool, _detail: &str) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn next_attempt_delay(&mut self, _task: &[u8], _on_success: bool) -> Result<std::time::Duration, ()> {
+		Err(())
+	}
 }
 
 #[cfg(test)]
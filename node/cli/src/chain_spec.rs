@@ -21,6 +21,7 @@ use node_primitives::AccountId;
 use node_runtime::{ConsensusConfig, CouncilSeatsConfig, CouncilVotingConfig, DemocracyConfig,
 	SessionConfig, StakingConfig, StakerStatus, TimestampConfig, BalancesConfig, TreasuryConfig,
 	SudoConfig, ContractConfig, GrandpaConfig, IndicesConfig, Permill, Perbill};
+use node_runtime::currency::{MILLICENTS, CENTS, DOLLARS};
 pub use node_runtime::GenesisConfig;
 use substrate_service;
 use hex_literal::hex;
@@ -66,10 +67,6 @@ fn staging_testnet_config_genesis() -> GenesisConfig {
 		hex!["9ee5e5bdc0ec239eb164f865ecc345ce4c88e76ee002e0f7e318097347471809"].unchecked_into(), // 5Ff3iXP75ruzroPWRP2FYBHWnmGGBSb63857BgnzCoXNxfPo
 	];
 
-	const MILLICENTS: u128 = 1_000_000_000;
-	const CENTS: u128 = 1_000 * MILLICENTS;    // assume this is worth about a cent.
-	const DOLLARS: u128 = 100 * CENTS;
-
 	const SECS_PER_BLOCK: u64 = 6;
 	const MINUTES: u64 = 60 / SECS_PER_BLOCK;
 	const HOURS: u64 = MINUTES * 60;
@@ -137,11 +134,17 @@ fn staging_testnet_config_genesis() -> GenesisConfig {
 			term_duration: 28 * DAYS,
 			desired_seats: 0,
 			inactive_grace_period: 1,    // one additional vote should go by before an inactive voter can be reaped.
+			max_delegation_depth: 3,
 		}),
 		council_voting: Some(CouncilVotingConfig {
 			cooloff_period: 4 * DAYS,
 			voting_period: 1 * DAYS,
 			enact_delay_period: 0,
+			quorum: 0,
+			max_cooloff_period: 28 * DAYS,
+			postponement_threshold: 0,
+			proposal_deposit_base: 10 * DOLLARS,
+			deposit_per_byte: 1 * CENTS,
 		}),
 		timestamp: Some(TimestampConfig {
 			minimum_period: SECS_PER_BLOCK / 2, // due to the nature of aura the slots are 2*period
@@ -323,11 +326,17 @@ pub fn testnet_genesis(
 			term_duration: 1000000,
 			desired_seats: (endowed_accounts.len() / 2 - initial_authorities.len()) as u32,
 			inactive_grace_period: 1,
+			max_delegation_depth: 3,
 		}),
 		council_voting: Some(CouncilVotingConfig {
 			cooloff_period: 75,
 			voting_period: 20,
 			enact_delay_period: 0,
+			quorum: 0,
+			max_cooloff_period: 750,
+			postponement_threshold: 0,
+			proposal_deposit_base: 0,
+			deposit_per_byte: 0,
 		}),
 		timestamp: Some(TimestampConfig {
 			minimum_period: 2,                    // 2*2=4 second block time.
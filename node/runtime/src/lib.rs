@@ -21,7 +21,7 @@
 #![recursion_limit="256"]
 
 use rstd::prelude::*;
-use support::construct_runtime;
+use support::{construct_runtime, parameter_types};
 use substrate_primitives::u32_trait::{_2, _4};
 use node_primitives::{
 	AccountId, AccountIndex, Balance, BlockNumber, Hash, Index, AuthorityId, Signature, AuthoritySignature
@@ -53,13 +53,23 @@ pub use runtime_primitives::{Permill, Perbill};
 pub use support::StorageValue;
 pub use staking::StakerStatus;
 
+/// Denominations of the native currency.
+pub mod currency {
+	use node_primitives::Balance;
+
+	pub const MILLICENTS: Balance = 1_000_000_000;
+	pub const CENTS: Balance = 1_000 * MILLICENTS;    // assume this is worth about a cent.
+	pub const DOLLARS: Balance = 100 * CENTS;
+}
+use currency::DOLLARS;
+
 /// Runtime version.
 pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("node"),
 	impl_name: create_runtime_str!("substrate-node"),
 	authoring_version: 10,
-	spec_version: 88,
-	impl_version: 90,
+	spec_version: 151,
+	impl_version: 153,
 	apis: RUNTIME_API_VERSIONS,
 };
 
@@ -156,20 +166,54 @@ impl democracy::Trait for Runtime {
 	type Event = Event;
 }
 
+parameter_types! {
+	pub const MaxCandidates: u32 = 64;
+	pub const ApprovalDecayPeriod: council::VoteIndex = 0;
+	pub const MaxSlashHistory: u32 = 16;
+	pub const MinimumTurnout: Permill = Permill::zero();
+	pub const DelegationBond: Balance = 1 * DOLLARS;
+	pub const MaxDelegators: u32 = 16;
+	pub const MaxExecutionRetries: u32 = 3;
+	pub const RetryDelay: BlockNumber = 10;
+	pub const DeadlineMinimumSupport: u32 = 3;
+	pub const MaxJustificationLen: u32 = 256;
+	pub const MotionDuration: BlockNumber = 100_800;
+	pub const MotionDeposit: Balance = 10 * DOLLARS;
+	pub const VetoOverrideThreshold: Perbill = Perbill::from_percent(67);
+	pub const MinEnactDelay: BlockNumber = 10;
+}
+
 impl council::Trait for Runtime {
 	type Event = Event;
 	type BadPresentation = ();
 	type BadReaper = ();
+	type MaxCandidates = MaxCandidates;
+	type ApprovalDecayPeriod = ApprovalDecayPeriod;
+	type MaxSlashHistory = MaxSlashHistory;
+	type MinimumTurnout = MinimumTurnout;
+	type DelegationBond = DelegationBond;
+	type MaxDelegators = MaxDelegators;
 }
 
 impl council::voting::Trait for Runtime {
 	type Event = Event;
+	type VoteWeighting = ();
+	type VetoOverrideThreshold = VetoOverrideThreshold;
+	type MinEnactDelay = MinEnactDelay;
 }
 
 impl council::motions::Trait for Runtime {
 	type Origin = Origin;
 	type Proposal = Call;
 	type Event = Event;
+	type MaxExecutionRetries = MaxExecutionRetries;
+	type RetryDelay = RetryDelay;
+	type RetryableError = ();
+	type DeadlineMinimumSupport = DeadlineMinimumSupport;
+	type MaxJustificationLen = MaxJustificationLen;
+	type MotionDuration = MotionDuration;
+	type ProposalDeposit = MotionDeposit;
+	type MotionDepositSlashed = ();
 }
 
 impl treasury::Trait for Runtime {
@@ -29,7 +29,8 @@ mod tests {
 	// These re-exports are here for a reason, edit with care
 	pub use super::*;
 	pub use runtime_io::with_externalities;
-	use srml_support::{impl_outer_origin, impl_outer_event, impl_outer_dispatch};
+	use std::cell::RefCell;
+	use srml_support::{impl_outer_origin, impl_outer_event, impl_outer_dispatch, parameter_types};
 	pub use substrate_primitives::H256;
 	pub use primitives::BuildStorage;
 	pub use primitives::traits::{BlakeTwo256, IdentityLookup};
@@ -53,6 +54,89 @@ mod tests {
 		pub enum Call for Test where origin: Origin {
 			balances::Balances,
 			democracy::Democracy,
+			dummy::Dummy,
+		}
+	}
+
+	/// A module whose single call fails until it is made ready, used as the target of motions.
+	pub mod dummy {
+		use srml_support::{decl_module, decl_storage, ensure, dispatch::Result};
+		use primitives::traits::EnsureOrigin;
+
+		pub trait Trait: system::Trait {
+			/// The origin which may `act_as_council`.
+			type MembersOrigin: EnsureOrigin<Self::Origin>;
+		}
+
+		decl_storage! {
+			trait Store for Module<T: Trait> as Dummy {
+				pub Ready get(ready): bool;
+			}
+		}
+
+		decl_module! {
+			pub struct Module<T: Trait> for enum Call where origin: T::Origin {
+				fn act(origin) -> Result {
+					let _ = origin;
+					ensure!(Self::ready(), "not ready yet");
+					Ok(())
+				}
+
+				fn act_as_council(origin) -> Result {
+					T::MembersOrigin::ensure_origin(origin)?;
+					Ok(())
+				}
+			}
+		}
+	}
+
+	thread_local! {
+		pub static STAKE_WEIGHTED_VOTES: RefCell<bool> = RefCell::new(false);
+		pub static APPROVAL_DECAY_PERIOD: RefCell<u32> = RefCell::new(0);
+		pub static MINIMUM_TURNOUT: RefCell<u32> = RefCell::new(0);
+		pub static MOTION_DEPOSIT: RefCell<u64> = RefCell::new(0);
+	}
+
+	/// No decay of approval stakes, unless `APPROVAL_DECAY_PERIOD` is set.
+	pub struct ApprovalDecayPeriod;
+	impl srml_support::traits::Get<u32> for ApprovalDecayPeriod {
+		fn get() -> u32 {
+			APPROVAL_DECAY_PERIOD.with(|p| *p.borrow())
+		}
+	}
+
+	/// No minimum turnout, unless `MINIMUM_TURNOUT` is set to a percentage.
+	pub struct MinimumTurnout;
+	impl srml_support::traits::Get<primitives::Permill> for MinimumTurnout {
+		fn get() -> primitives::Permill {
+			primitives::Permill::from_percent(MINIMUM_TURNOUT.with(|t| *t.borrow()))
+		}
+	}
+
+	/// No deposit for motions, unless `MOTION_DEPOSIT` is set.
+	pub struct MotionDeposit;
+	impl srml_support::traits::Get<u64> for MotionDeposit {
+		fn get() -> u64 {
+			MOTION_DEPOSIT.with(|d| *d.borrow())
+		}
+	}
+
+	/// One vote per councillor, unless `STAKE_WEIGHTED_VOTES` is set.
+	pub struct TestVoteWeighting;
+	impl voting::VoteWeight<Test> for TestVoteWeighting {
+		fn vote_weight(who: &u64) -> Option<u128> {
+			if STAKE_WEIGHTED_VOTES.with(|w| *w.borrow()) {
+				<voting::StakeWeighted as voting::VoteWeight<Test>>::vote_weight(who)
+			} else {
+				None
+			}
+		}
+	}
+
+	pub struct NotReadyIsRetryable;
+	impl motions::RetryableError for NotReadyIsRetryable {
+		fn is_retryable(error: &'static str) -> bool {
+			error == "not ready yet"
 		}
 	}
 
@@ -86,18 +170,51 @@ mod tests {
 		type Proposal = Call;
 		type Event = Event;
 	}
+	parameter_types! {
+		pub const MaxCandidates: u32 = 5;
+		pub const MaxSlashHistory: u32 = 2;
+		pub const MaxExecutionRetries: u32 = 2;
+		pub const RetryDelay: u64 = 2;
+		pub const DeadlineMinimumSupport: u32 = 2;
+		pub const MaxJustificationLen: u32 = 8;
+		pub const MotionDuration: u64 = 100;
+		pub const VetoOverrideThreshold: primitives::Perbill = primitives::Perbill::from_percent(67);
+		pub const MinEnactDelay: u64 = 1;
+		pub const DelegationBond: u64 = 2;
+		pub const MaxDelegators: u32 = 2;
+	}
 	impl seats::Trait for Test {
 		type Event = Event;
 		type BadPresentation = ();
 		type BadReaper = ();
+		type MaxCandidates = MaxCandidates;
+		type ApprovalDecayPeriod = ApprovalDecayPeriod;
+		type MaxSlashHistory = MaxSlashHistory;
+		type MinimumTurnout = MinimumTurnout;
+		type DelegationBond = DelegationBond;
+		type MaxDelegators = MaxDelegators;
 	}
 	impl motions::Trait for Test {
 		type Origin = Origin;
 		type Proposal = Call;
 		type Event = Event;
+		type MaxExecutionRetries = MaxExecutionRetries;
+		type RetryDelay = RetryDelay;
+		type RetryableError = NotReadyIsRetryable;
+		type DeadlineMinimumSupport = DeadlineMinimumSupport;
+		type MaxJustificationLen = MaxJustificationLen;
+		type MotionDuration = MotionDuration;
+		type ProposalDeposit = MotionDeposit;
+		type MotionDepositSlashed = ();
+	}
+	impl dummy::Trait for Test {
+		type MembersOrigin = motions::EnsureMembers<substrate_primitives::u32_trait::_3>;
 	}
 	impl voting::Trait for Test {
 		type Event = Event;
+		type VoteWeighting = TestVoteWeighting;
+		type VetoOverrideThreshold = VetoOverrideThreshold;
+		type MinEnactDelay = MinEnactDelay;
 	}
 
 	pub fn new_test_ext(with_council: bool) -> runtime_io::TestExternalities<Blake2Hasher> {
@@ -133,11 +250,17 @@ mod tests {
 			presentation_duration: 2,
 			desired_seats: 2,
 			term_duration: 5,
+			max_delegation_depth: 2,
 		}.build_storage().unwrap().0);
 		t.extend(voting::GenesisConfig::<Test> {
 			cooloff_period: 2,
 			voting_period: 1,
 			enact_delay_period: 0,
+			quorum: 0,
+			max_cooloff_period: 5,
+			postponement_threshold: 0,
+			proposal_deposit_base: 0,
+			deposit_per_byte: 0,
 		}.build_storage().unwrap().0);
 		runtime_io::TestExternalities::new(t)
 	}
@@ -148,4 +271,5 @@ mod tests {
 	pub type Council = seats::Module<Test>;
 	pub type CouncilVoting = voting::Module<Test>;
 	pub type CouncilMotions = motions::Module<Test>;
+	pub type Dummy = dummy::Module<Test>;
 }
@@ -19,15 +19,22 @@
 use rstd::prelude::*;
 use rstd::result;
 use substrate_primitives::u32_trait::Value as U32;
-use primitives::traits::{Hash, EnsureOrigin};
+use primitives::traits::{Hash, EnsureOrigin, One};
 use srml_support::dispatch::{Dispatchable, Parameter};
 use srml_support::{StorageValue, StorageMap, decl_module, decl_event, decl_storage, ensure};
+use srml_support::traits::{Get, Currency, ReservableCurrency, OnUnbalanced};
 use super::{Trait as CouncilTrait, Module as Council};
 use system::{self, ensure_signed};
 
+type BalanceOf<T> = <<T as democracy::Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as democracy::Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::NegativeImbalance;
+
 /// Simple index type for proposal counting.
 pub type ProposalIndex = u32;
 
+/// The most expired motions discarded in a single block. Any more are left to later blocks.
+pub const MAX_EXPIRIES_PER_BLOCK: usize = 16;
+
 pub trait Trait: CouncilTrait {
 	/// The outer origin type.
 	type Origin: From<Origin>;
@@ -37,6 +44,54 @@ pub trait Trait: CouncilTrait {
 
 	/// The outer event type.
 	type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+	/// The maximum number of times the execution of an approved motion is retried.
+	type MaxExecutionRetries: Get<u32>;
+
+	/// The number of blocks to wait before retrying the execution of an approved motion.
+	type RetryDelay: Get<Self::BlockNumber>;
+
+	/// Decides which dispatch errors of an approved motion are transient and worth a retry.
+	type RetryableError: RetryableError;
+
+	/// The number of yes votes a motion proposed with a deadline needs by then to be executed
+	/// even though it has not reached its threshold.
+	type DeadlineMinimumSupport: Get<u32>;
+
+	/// The maximum length in bytes of the justification a motion may be proposed with.
+	type MaxJustificationLen: Get<u32>;
+
+	/// The number of blocks after which a motion still undecided is discarded.
+	type MotionDuration: Get<Self::BlockNumber>;
+
+	/// The deposit reserved from the proposer of a motion, returned unless the motion fails
+	/// without a single councillor other than the proposer voting for it.
+	type ProposalDeposit: Get<BalanceOf<Self>>;
+
+	/// Handler for the unbalanced reduction when slashing the deposit of a failed motion.
+	type MotionDepositSlashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+}
+
+/// Classification of the errors with which an approved motion may fail to execute.
+pub trait RetryableError {
+	/// Whether the execution which failed with `error` may succeed if retried later.
+	fn is_retryable(error: &'static str) -> bool;
+}
+
+impl RetryableError for () {
+	fn is_retryable(_error: &'static str) -> bool { false }
+}
+
+/// The position of a councillor on a motion.
+#[derive(PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum Vote {
+	/// Voted in favour of the motion.
+	Yes,
+	/// Voted against the motion.
+	No,
+	/// Has not voted on the motion.
+	NotVoted,
 }
 
 /// Origin for the council module.
@@ -48,7 +103,12 @@ pub enum Origin {
 }
 
 decl_event!(
-	pub enum Event<T> where <T as system::Trait>::Hash, <T as system::Trait>::AccountId {
+	pub enum Event<T> where
+		<T as system::Trait>::Hash,
+		<T as system::Trait>::AccountId,
+		<T as system::Trait>::BlockNumber,
+		Balance = BalanceOf<T>
+	{
 		/// A motion (given hash) has been proposed (by given account) with a threshold (given u32).
 		Proposed(AccountId, ProposalIndex, Hash, u32),
 		/// A motion (given hash) has been voted on by given account, leaving
@@ -60,6 +120,21 @@ decl_event!(
 		Disapproved(Hash),
 		/// A motion was executed; `bool` is true if returned without error.
 		Executed(Hash, bool),
+		/// The execution of a motion failed transiently and will be retried at the given block.
+		ExecutionRetryScheduled(Hash, BlockNumber),
+		/// A motion reached its deadline without meeting its threshold but with enough
+		/// support to be executed anyway.
+		DeadlineReached(Hash),
+		/// A motion was closed early with its outcome decided by a tally (yes votes and no votes
+		/// given as u32s respectively).
+		Closed(Hash, u32, u32),
+		/// A motion was discarded since it was still undecided at its expiry.
+		MotionExpired(Hash),
+		/// A motion (first hash) was amended, becoming a new motion (second hash).
+		MotionAmended(Hash, Hash),
+		/// The deposit of a proposer (given account) was slashed since their motion failed without
+		/// support.
+		DepositSlashed(AccountId, Balance),
 	}
 );
 
@@ -68,25 +143,51 @@ decl_module! {
 		fn deposit_event<T>() = default;
 		fn propose(origin, #[compact] threshold: u32, proposal: Box<<T as Trait>::Proposal>) {
 			let who = ensure_signed(origin)?;
+			Self::do_propose(who, threshold, proposal, None, Vec::new(), None)?;
+		}
 
-			ensure!(Self::is_councillor(&who), "proposer not on council");
-
-			let proposal_hash = T::Hashing::hash_of(&proposal);
-
-			ensure!(!<ProposalOf<T>>::exists(proposal_hash), "duplicate proposals not allowed");
+		/// Propose a motion which may only be approved once at least `quorum` councillors have
+		/// voted on it either way, in addition to `threshold` of them voting in favour. It is
+		/// disapproved as soon as the council is too small to reach `quorum`.
+		fn propose_with_quorum(
+			origin,
+			#[compact] quorum: u32,
+			#[compact] threshold: u32,
+			proposal: Box<<T as Trait>::Proposal>
+		) {
+			let who = ensure_signed(origin)?;
+			ensure!(quorum >= threshold, "quorum below threshold");
+			ensure!(quorum <= <Council<T>>::active_council().len() as u32, "quorum above council size");
+			Self::do_propose(who, threshold, proposal, None, Vec::new(), Some(quorum))?;
+		}
 
-			if threshold < 2 {
-				let ok = proposal.dispatch(Origin::Members(1).into()).is_ok();
-				Self::deposit_event(RawEvent::Executed(proposal_hash, ok));
-			} else {
-				let index = Self::proposal_count();
-				<ProposalCount<T>>::mutate(|i| *i += 1);
-				<Proposals<T>>::mutate(|proposals| proposals.push(proposal_hash));
-				<ProposalOf<T>>::insert(proposal_hash, *proposal);
-				<Voting<T>>::insert(proposal_hash, (index, threshold, vec![who.clone()], vec![]));
+		/// Propose a motion along with a short justification for it, kept on-chain for as long
+		/// as the motion is open.
+		fn propose_with_justification(
+			origin,
+			#[compact] threshold: u32,
+			proposal: Box<<T as Trait>::Proposal>,
+			justification: Vec<u8>
+		) {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				justification.len() <= T::MaxJustificationLen::get() as usize,
+				"justification too long"
+			);
+			Self::do_propose(who, threshold, proposal, None, justification, None)?;
+		}
 
-				Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
-			}
+		/// Propose a motion which, should it not have reached `threshold` by block `execute_by`,
+		/// is executed then anyway provided it has at least `DeadlineMinimumSupport` yes votes.
+		fn propose_with_deadline(
+			origin,
+			#[compact] threshold: u32,
+			proposal: Box<<T as Trait>::Proposal>,
+			#[compact] execute_by: T::BlockNumber
+		) {
+			let who = ensure_signed(origin)?;
+			ensure!(execute_by > <system::Module<T>>::block_number(), "deadline must be in the future");
+			Self::do_propose(who, threshold, proposal, Some(execute_by), Vec::new(), None)?;
 		}
 
 		fn vote(origin, proposal: T::Hash, #[compact] index: ProposalIndex, approve: bool) {
@@ -99,6 +200,9 @@ decl_module! {
 
 			let position_yes = voting.2.iter().position(|a| a == &who);
 			let position_no = voting.3.iter().position(|a| a == &who);
+			if position_yes.is_none() && position_no.is_none() {
+				<Council<T>>::note_motion_voted(&who);
+			}
 
 			if approve {
 				if position_yes.is_none() {
@@ -126,30 +230,90 @@ decl_module! {
 
 			let threshold = voting.1;
 			let potential_votes = <Council<T>>::active_council().len() as u32;
-			let approved = yes_votes >= threshold;
-			let disapproved = potential_votes.saturating_sub(no_votes) < threshold;
+			let quorum = Self::quorum_of(&proposal).unwrap_or(threshold);
+			let approved = yes_votes >= threshold && yes_votes + no_votes >= quorum;
+			let disapproved = potential_votes.saturating_sub(no_votes) < threshold
+				|| potential_votes < quorum;
 			if approved || disapproved {
-				if approved {
-					Self::deposit_event(RawEvent::Approved(proposal));
-
-					// execute motion, assuming it exists.
-					if let Some(p) = <ProposalOf<T>>::take(&proposal) {
-						let ok = p.dispatch(Origin::Members(threshold).into()).is_ok();
-						Self::deposit_event(RawEvent::Executed(proposal, ok));
-					}
-				} else {
-					// disapproved
-					Self::deposit_event(RawEvent::Disapproved(proposal));
-				}
-
-				// remove vote
-				<Voting<T>>::remove(&proposal);
-				<Proposals<T>>::mutate(|proposals| proposals.retain(|h| h != &proposal));
+				Self::resolve(proposal, threshold, approved, yes_votes);
 			} else {
 				// update voting
 				<Voting<T>>::insert(&proposal, voting);
 			}
 		}
+
+		/// Replace the sender's motion `old_hash` with `new_proposal`, keeping its index, threshold
+		/// and any deadline, quorum, justification, expiry and deposit. Only possible while no one
+		/// else has voted on it.
+		fn amend(origin, old_hash: T::Hash, new_proposal: Box<<T as Trait>::Proposal>) {
+			let who = ensure_signed(origin)?;
+
+			let (proposer, deposit) = Self::deposit_of(&old_hash).ok_or("proposal must exist")?;
+			ensure!(proposer == who, "only the proposer may amend a motion");
+			let voting = Self::voting(&old_hash).ok_or("proposal must exist")?;
+			ensure!(voting.2 == vec![who.clone()] && voting.3.is_empty(), "motion already voted on");
+
+			let new_hash = T::Hashing::hash_of(&new_proposal);
+			ensure!(!<ProposalOf<T>>::exists(new_hash), "duplicate proposals not allowed");
+
+			<Proposals<T>>::mutate(|proposals| for h in proposals.iter_mut() {
+				if *h == old_hash {
+					*h = new_hash;
+				}
+			});
+			<ProposalOf<T>>::remove(&old_hash);
+			<ProposalOf<T>>::insert(&new_hash, *new_proposal);
+			<Voting<T>>::remove(&old_hash);
+			<Voting<T>>::insert(&new_hash, voting);
+			<DepositOf<T>>::remove(&old_hash);
+			<DepositOf<T>>::insert(&new_hash, (who, deposit));
+			if let Some(when) = <ExecuteBy<T>>::take(&old_hash) {
+				<ExecuteBy<T>>::insert(&new_hash, when);
+				<Deadlines<T>>::mutate(when, |hashes| hashes.push(new_hash));
+			}
+			if let Some(expiry) = <MotionExpiry<T>>::take(&old_hash) {
+				<MotionExpiry<T>>::insert(&new_hash, expiry);
+				<Expiries<T>>::mutate(expiry, |hashes| hashes.push(new_hash));
+			}
+			if <ProposalJustification<T>>::exists(&old_hash) {
+				<ProposalJustification<T>>::insert(&new_hash, <ProposalJustification<T>>::take(&old_hash));
+			}
+			if let Some(quorum) = <QuorumOf<T>>::take(&old_hash) {
+				<QuorumOf<T>>::insert(&new_hash, quorum);
+			}
+
+			Self::deposit_event(RawEvent::MotionAmended(old_hash, new_hash));
+		}
+
+		/// Close a motion whose outcome can no longer be changed by the councillors yet to vote,
+		/// e.g. because the council has shrunk since it was proposed.
+		fn close(origin, proposal: T::Hash, #[compact] index: ProposalIndex) {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_councillor(&who), "closer not on council");
+
+			let voting = Self::voting(&proposal).ok_or("proposal must exist")?;
+			ensure!(voting.0 == index, "mismatched index");
+
+			let yes_votes = voting.2.len() as u32;
+			let no_votes = voting.3.len() as u32;
+			let threshold = voting.1;
+			let potential_votes = <Council<T>>::active_council().len() as u32;
+			let quorum = Self::quorum_of(&proposal).unwrap_or(threshold);
+			let approved = yes_votes >= threshold && yes_votes + no_votes >= quorum;
+			let disapproved = potential_votes.saturating_sub(no_votes) < threshold
+				|| potential_votes < quorum;
+			ensure!(approved || disapproved, "motion not yet decided");
+
+			Self::deposit_event(RawEvent::Closed(proposal, yes_votes, no_votes));
+			Self::resolve(proposal, threshold, approved, yes_votes);
+		}
+
+		fn on_finalize(n: T::BlockNumber) {
+			Self::retry_executions(n);
+			Self::enforce_deadlines(n);
+			Self::expire_motions(n);
+		}
 	}
 }
 
@@ -163,6 +327,25 @@ decl_storage! {
 		pub Voting get(voting): map T::Hash => Option<(ProposalIndex, u32, Vec<T::AccountId>, Vec<T::AccountId>)>;
 		/// Proposals so far.
 		pub ProposalCount get(proposal_count): u32;
+		/// Approved motions whose execution is to be retried at a given block: (proposal hash,
+		/// threshold it was approved with, failed attempts so far).
+		pub ExecutionRetries get(execution_retries): map T::BlockNumber => Vec<(T::Hash, u32, u32)>;
+		/// The block by which a motion proposed with a deadline is to be decided.
+		pub ExecuteBy get(execute_by): map T::Hash => Option<T::BlockNumber>;
+		/// The motions whose deadline falls on a given block.
+		pub Deadlines get(deadlines): map T::BlockNumber => Vec<T::Hash>;
+		/// The justification an open motion was proposed with, if any.
+		pub ProposalJustification get(justification_of): map T::Hash => Vec<u8>;
+		/// The number of councillors who must have voted on a motion before it may be approved, if
+		/// other than its threshold.
+		pub QuorumOf get(quorum_of): map T::Hash => Option<u32>;
+		/// The block at which an open motion expires if still undecided.
+		pub MotionExpiry get(expiry_of): map T::Hash => Option<T::BlockNumber>;
+		/// The motions due to expire at a given block, including any which could not be discarded
+		/// at earlier blocks.
+		pub Expiries get(expiries): map T::BlockNumber => Vec<T::Hash>;
+		/// The proposer of an open motion and the deposit reserved from them for it.
+		pub DepositOf get(deposit_of): map T::Hash => Option<(T::AccountId, BalanceOf<T>)>;
 	}
 	add_extra_genesis {
 		build(|_, _, _| {});
@@ -174,6 +357,199 @@ impl<T: Trait> Module<T> {
 		<Council<T>>::active_council().iter()
 			.any(|&(ref a, _)| a == who)
 	}
+
+	/// Each current councillor along with their vote on the motion `proposal_hash`.
+	pub fn votes_on(proposal_hash: &T::Hash) -> Vec<(T::AccountId, Vote)> {
+		let voting = Self::voting(proposal_hash);
+		<Council<T>>::active_council().into_iter()
+			.map(|(who, _)| {
+				let vote = match voting {
+					Some((_, _, ref yes, _)) if yes.contains(&who) => Vote::Yes,
+					Some((_, _, _, ref no)) if no.contains(&who) => Vote::No,
+					_ => Vote::NotVoted,
+				};
+				(who, vote)
+			})
+			.collect()
+	}
+
+	/// Whether there are still enough councillors who have not voted against the open motion
+	/// `proposal_hash` for it to reach its threshold. `false` if there is no such motion.
+	pub fn threshold_reachable(proposal_hash: &T::Hash) -> bool {
+		let potential_votes = <Council<T>>::active_council().len() as u32;
+		Self::voting(proposal_hash).map_or(false, |(_, threshold, _, no)|
+			potential_votes.saturating_sub(no.len() as u32) >= threshold
+		)
+	}
+
+	/// Approve or disapprove the open motion `proposal`, which has `yes_votes`, executing it if
+	/// approved, and forget about its voting.
+	fn resolve(proposal: T::Hash, threshold: u32, approved: bool, yes_votes: u32) {
+		Self::settle_deposit(&proposal, approved || yes_votes > 1);
+		if approved {
+			Self::deposit_event(RawEvent::Approved(proposal));
+
+			// execute motion, assuming it exists.
+			if let Some(p) = <ProposalOf<T>>::take(&proposal) {
+				Self::execute(proposal, p, threshold, 0);
+			}
+		} else {
+			// disapproved
+			Self::deposit_event(RawEvent::Disapproved(proposal));
+		}
+
+		// remove vote
+		<Voting<T>>::remove(&proposal);
+		<ExecuteBy<T>>::remove(&proposal);
+		<ProposalJustification<T>>::remove(&proposal);
+		<QuorumOf<T>>::remove(&proposal);
+		<MotionExpiry<T>>::remove(&proposal);
+		<Proposals<T>>::mutate(|proposals| proposals.retain(|h| h != &proposal));
+	}
+
+	fn do_propose(
+		who: T::AccountId,
+		threshold: u32,
+		proposal: Box<<T as Trait>::Proposal>,
+		execute_by: Option<T::BlockNumber>,
+		justification: Vec<u8>,
+		quorum: Option<u32>,
+	) -> result::Result<(), &'static str> {
+		ensure!(Self::is_councillor(&who), "proposer not on council");
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+
+		ensure!(!<ProposalOf<T>>::exists(proposal_hash), "duplicate proposals not allowed");
+
+		if threshold < 2 && quorum.unwrap_or(threshold) < 2 {
+			let ok = proposal.dispatch(Origin::Members(1).into()).is_ok();
+			Self::deposit_event(RawEvent::Executed(proposal_hash, ok));
+		} else {
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&who, deposit).map_err(|_| "proposer's balance too low")?;
+			<DepositOf<T>>::insert(proposal_hash, (who.clone(), deposit));
+			let index = Self::proposal_count();
+			<ProposalCount<T>>::mutate(|i| *i += 1);
+			<Proposals<T>>::mutate(|proposals| proposals.push(proposal_hash));
+			<ProposalOf<T>>::insert(proposal_hash, *proposal);
+			<Voting<T>>::insert(proposal_hash, (index, threshold, vec![who.clone()], vec![]));
+			if let Some(when) = execute_by {
+				<ExecuteBy<T>>::insert(proposal_hash, when);
+				<Deadlines<T>>::mutate(when, |hashes| hashes.push(proposal_hash));
+			}
+			if !justification.is_empty() {
+				<ProposalJustification<T>>::insert(proposal_hash, justification);
+			}
+			if let Some(quorum) = quorum.filter(|&q| q != threshold) {
+				<QuorumOf<T>>::insert(proposal_hash, quorum);
+			}
+			let expiry = <system::Module<T>>::block_number() + T::MotionDuration::get();
+			<MotionExpiry<T>>::insert(proposal_hash, expiry);
+			<Expiries<T>>::mutate(expiry, |hashes| hashes.push(proposal_hash));
+			<Council<T>>::note_motion_opened();
+			<Council<T>>::note_motion_voted(&who);
+
+			Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
+		}
+		Ok(())
+	}
+
+	/// Decide the motions whose deadline is `now`: those with enough support are executed as
+	/// though they had reached their threshold, the rest are disapproved.
+	fn enforce_deadlines(now: T::BlockNumber) {
+		for proposal_hash in <Deadlines<T>>::take(now) {
+			if Self::execute_by(&proposal_hash) != Some(now) {
+				// decided before its deadline.
+				continue;
+			}
+			<ExecuteBy<T>>::remove(&proposal_hash);
+			<ProposalJustification<T>>::remove(&proposal_hash);
+			<QuorumOf<T>>::remove(&proposal_hash);
+			<MotionExpiry<T>>::remove(&proposal_hash);
+			let voting = match <Voting<T>>::take(&proposal_hash) {
+				Some(voting) => voting,
+				None => continue,
+			};
+			<Proposals<T>>::mutate(|proposals| proposals.retain(|h| h != &proposal_hash));
+
+			let yes_votes = voting.2.len() as u32;
+			let supported = yes_votes >= T::DeadlineMinimumSupport::get();
+			Self::settle_deposit(&proposal_hash, supported || yes_votes > 1);
+			if supported {
+				Self::deposit_event(RawEvent::DeadlineReached(proposal_hash));
+				// only the councillors who voted for it condone the motion, not its threshold.
+				if let Some(p) = <ProposalOf<T>>::take(&proposal_hash) {
+					Self::execute(proposal_hash, p, yes_votes, 0);
+				}
+			} else {
+				<ProposalOf<T>>::remove(&proposal_hash);
+				Self::deposit_event(RawEvent::Disapproved(proposal_hash));
+			}
+		}
+	}
+
+	/// Return the deposit of the motion `proposal_hash` to its proposer if `refund`, otherwise
+	/// slash it. Taking the deposit ensures it is settled only once however the motion ends.
+	fn settle_deposit(proposal_hash: &T::Hash, refund: bool) {
+		if let Some((who, deposit)) = <DepositOf<T>>::take(proposal_hash) {
+			if refund {
+				T::Currency::unreserve(&who, deposit);
+			} else {
+				T::MotionDepositSlashed::on_unbalanced(T::Currency::slash_reserved(&who, deposit).0);
+				Self::deposit_event(RawEvent::DepositSlashed(who, deposit));
+			}
+		}
+	}
+
+	/// Discard the motions which have expired by `now` while still undecided, at most
+	/// `MAX_EXPIRIES_PER_BLOCK` of them. The rest are put off to the next block.
+	fn expire_motions(now: T::BlockNumber) {
+		let mut expiring = <Expiries<T>>::take(now);
+		if expiring.len() > MAX_EXPIRIES_PER_BLOCK {
+			let mut later = expiring.split_off(MAX_EXPIRIES_PER_BLOCK);
+			<Expiries<T>>::mutate(now + One::one(), |hashes| {
+				later.append(hashes);
+				*hashes = later;
+			});
+		}
+		for proposal_hash in expiring {
+			if Self::expiry_of(&proposal_hash).map_or(true, |expiry| expiry > now) {
+				// decided, or since proposed anew.
+				continue;
+			}
+			<MotionExpiry<T>>::remove(&proposal_hash);
+			let yes_votes = <Voting<T>>::take(&proposal_hash).map_or(0, |voting| voting.2.len());
+			Self::settle_deposit(&proposal_hash, yes_votes > 1);
+			<ProposalOf<T>>::remove(&proposal_hash);
+			<ExecuteBy<T>>::remove(&proposal_hash);
+			<ProposalJustification<T>>::remove(&proposal_hash);
+			<QuorumOf<T>>::remove(&proposal_hash);
+			<Proposals<T>>::mutate(|proposals| proposals.retain(|h| h != &proposal_hash));
+			Self::deposit_event(RawEvent::MotionExpired(proposal_hash));
+		}
+	}
+
+	/// Dispatch an approved motion as condoned by `threshold` council members. A transient failure
+	/// is retried later, up to `MaxExecutionRetries` times, while the proposal is kept around.
+	fn execute(proposal_hash: T::Hash, proposal: <T as Trait>::Proposal, threshold: u32, attempts: u32) {
+		match proposal.clone().dispatch(Origin::Members(threshold).into()) {
+			Err(e) if T::RetryableError::is_retryable(e) && attempts < T::MaxExecutionRetries::get() => {
+				let when = <system::Module<T>>::block_number() + T::RetryDelay::get().max(One::one());
+				<ProposalOf<T>>::insert(proposal_hash, proposal);
+				<ExecutionRetries<T>>::mutate(when, |retries| retries.push((proposal_hash, threshold, attempts + 1)));
+				Self::deposit_event(RawEvent::ExecutionRetryScheduled(proposal_hash, when));
+			}
+			result => Self::deposit_event(RawEvent::Executed(proposal_hash, result.is_ok())),
+		}
+	}
+
+	fn retry_executions(now: T::BlockNumber) {
+		for (proposal_hash, threshold, attempts) in <ExecutionRetries<T>>::take(now) {
+			if let Some(p) = <ProposalOf<T>>::take(&proposal_hash) {
+				Self::execute(proposal_hash, p, threshold, attempts);
+			}
+		}
+	}
 }
 
 /// Ensure that the origin `o` represents at least `n` council members. Returns
@@ -362,4 +738,481 @@ mod tests {
 			]);
 		});
 	}
+
+	fn ready_proposal() -> Call {
+		Call::Dummy(dummy::Call::act())
+	}
+
+	#[test]
+	fn motions_transient_failure_should_be_retried() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 2, Box::new(proposal.clone())));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+
+			assert_eq!(CouncilMotions::execution_retries(3), vec![(hash, 2, 1)]);
+			assert_eq!(CouncilMotions::proposal_of(&hash), Some(proposal));
+			assert_eq!(
+				System::events().last().unwrap().event,
+				OuterEvent::motions(RawEvent::ExecutionRetryScheduled(hash, 3))
+			);
+
+			<dummy::Ready<Test>>::put(true);
+			System::set_block_number(3);
+			CouncilMotions::retry_executions(3);
+
+			assert_eq!(CouncilMotions::execution_retries(3), vec![]);
+			assert_eq!(CouncilMotions::proposal_of(&hash), None);
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::Executed(hash, true)));
+		});
+	}
+
+	#[test]
+	fn motions_retries_should_be_bounded() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 2, Box::new(proposal.clone())));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_eq!(CouncilMotions::execution_retries(3), vec![(hash, 2, 1)]);
+
+			System::set_block_number(3);
+			CouncilMotions::retry_executions(3);
+			assert_eq!(CouncilMotions::execution_retries(5), vec![(hash, 2, 2)]);
+
+			System::set_block_number(5);
+			CouncilMotions::retry_executions(5);
+			assert_eq!(CouncilMotions::execution_retries(5), vec![]);
+			assert_eq!(CouncilMotions::execution_retries(7), vec![]);
+			assert_eq!(CouncilMotions::proposal_of(&hash), None);
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::Executed(hash, false)));
+		});
+	}
+
+	#[test]
+	fn motions_with_deadline_execute_once_threshold_is_met() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			<dummy::Ready<Test>>::put(true);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_deadline(Origin::signed(1), 2, Box::new(proposal.clone()), 5));
+			assert_eq!(CouncilMotions::execute_by(&hash), Some(5));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::Executed(hash, true)));
+			assert_eq!(CouncilMotions::execute_by(&hash), None);
+
+			let events = System::events().len();
+			CouncilMotions::enforce_deadlines(5);
+			assert_eq!(System::events().len(), events);
+		});
+	}
+
+	#[test]
+	fn motions_with_deadline_execute_at_deadline_with_minimum_support() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			<dummy::Ready<Test>>::put(true);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_noop!(
+				CouncilMotions::propose_with_deadline(Origin::signed(1), 3, Box::new(proposal.clone()), 1),
+				"deadline must be in the future"
+			);
+			assert_ok!(CouncilMotions::propose_with_deadline(Origin::signed(1), 3, Box::new(proposal.clone()), 5));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_eq!(CouncilMotions::voting(&hash), Some((0, 3, vec![1, 2], vec![])));
+
+			System::set_block_number(5);
+			CouncilMotions::enforce_deadlines(5);
+
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::proposals(), vec![]);
+			assert_eq!(CouncilMotions::proposal_of(&hash), None);
+			let events: Vec<_> = System::events().into_iter().map(|r| r.event).rev().take(2).collect();
+			assert_eq!(events, vec![
+				OuterEvent::motions(RawEvent::Executed(hash, true)),
+				OuterEvent::motions(RawEvent::DeadlineReached(hash)),
+			]);
+		});
+	}
+
+	#[test]
+	fn motions_with_deadline_lacking_support_are_disapproved() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_deadline(Origin::signed(1), 3, Box::new(proposal.clone()), 5));
+
+			System::set_block_number(5);
+			CouncilMotions::enforce_deadlines(5);
+
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::proposal_of(&hash), None);
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::Disapproved(hash)));
+		});
+	}
+
+	#[test]
+	fn motions_justification_works() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_justification(Origin::signed(1), 3, Box::new(proposal.clone()), b"because".to_vec()));
+			assert_eq!(CouncilMotions::justification_of(&hash), b"because".to_vec());
+
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, false));
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::justification_of(&hash), Vec::<u8>::new());
+		});
+	}
+
+	#[test]
+	fn motions_over_length_justification_is_rejected() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_noop!(
+				CouncilMotions::propose_with_justification(Origin::signed(1), 3, Box::new(proposal.clone()), b"because so".to_vec()),
+				"justification too long"
+			);
+			assert_eq!(CouncilMotions::proposals(), Vec::<H256>::new());
+		});
+	}
+
+	#[test]
+	fn motions_without_quorum_stay_open() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			<dummy::Ready<Test>>::put(true);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_quorum(Origin::signed(1), 3, 2, Box::new(proposal.clone())));
+			assert_eq!(CouncilMotions::quorum_of(&hash), Some(3));
+
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_eq!(CouncilMotions::voting(&hash), Some((0, 2, vec![1, 2], Vec::<u64>::new())));
+			assert_eq!(CouncilMotions::proposals(), vec![hash]);
+
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hash.clone(), 0, false));
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::quorum_of(&hash), None);
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::Executed(hash, true)));
+		});
+	}
+
+	#[test]
+	fn motions_with_out_of_range_quorum_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_noop!(
+				CouncilMotions::propose_with_quorum(Origin::signed(1), 2, 3, Box::new(proposal.clone())),
+				"quorum below threshold"
+			);
+			assert_noop!(
+				CouncilMotions::propose_with_quorum(Origin::signed(1), 4, 2, Box::new(proposal)),
+				"quorum above council size"
+			);
+		});
+	}
+
+	#[test]
+	fn motions_are_disapproved_once_quorum_is_unreachable() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			<dummy::Ready<Test>>::put(true);
+			let proposal = ready_proposal();
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_quorum(Origin::signed(1), 3, 2, Box::new(proposal.clone())));
+
+			assert_ok!(Council::remove_member(3));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::proposals(), Vec::<H256>::new());
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::Disapproved(hash)));
+		});
+	}
+
+	#[test]
+	fn motions_threshold_reachable_works() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert!(!CouncilMotions::threshold_reachable(&hash));
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 3, Box::new(proposal.clone())));
+			assert!(CouncilMotions::threshold_reachable(&hash));
+
+			assert_ok!(Council::remove_member(3));
+			assert!(!CouncilMotions::threshold_reachable(&hash));
+		});
+	}
+
+	#[test]
+	fn motions_threshold_is_unreachable_with_enough_nays() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 2, Box::new(proposal.clone())));
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hash.clone(), 0, false));
+			assert!(CouncilMotions::threshold_reachable(&hash));
+
+			// of the two councillors left, one has voted against.
+			assert_ok!(Council::remove_member(2));
+			assert!(!CouncilMotions::threshold_reachable(&hash));
+		});
+	}
+
+	#[test]
+	fn motions_close_works() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 3, Box::new(proposal.clone())));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_noop!(CouncilMotions::close(Origin::signed(1), hash.clone(), 0), "motion not yet decided");
+
+			assert_ok!(Council::remove_member(3));
+			assert_noop!(CouncilMotions::close(Origin::signed(3), hash.clone(), 0), "closer not on council");
+			assert_ok!(CouncilMotions::close(Origin::signed(1), hash.clone(), 0));
+
+			assert_eq!(CouncilMotions::proposals(), vec![]);
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			let events = System::events();
+			assert_eq!(events[events.len() - 2].event, OuterEvent::motions(RawEvent::Closed(hash, 2, 0)));
+			assert_eq!(events[events.len() - 1].event, OuterEvent::motions(RawEvent::Disapproved(hash)));
+		});
+	}
+
+	#[test]
+	fn motions_votes_on_works() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_eq!(
+				CouncilMotions::votes_on(&hash),
+				vec![(1, Vote::NotVoted), (2, Vote::NotVoted), (3, Vote::NotVoted)]
+			);
+
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 2, Box::new(proposal.clone())));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, false));
+			assert_eq!(
+				CouncilMotions::votes_on(&hash),
+				vec![(1, Vote::Yes), (2, Vote::No), (3, Vote::NotVoted)]
+			);
+		});
+	}
+
+	#[test]
+	fn motions_expire_when_undecided() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 3, Box::new(proposal)));
+			assert_eq!(CouncilMotions::expiry_of(&hash), Some(101));
+
+			CouncilMotions::expire_motions(100);
+			assert_eq!(CouncilMotions::proposals(), vec![hash]);
+
+			System::set_block_number(101);
+			CouncilMotions::expire_motions(101);
+			assert_eq!(CouncilMotions::proposals(), Vec::<H256>::new());
+			assert_eq!(CouncilMotions::proposal_of(&hash), None);
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::expiry_of(&hash), None);
+			assert_eq!(System::events().last().unwrap().event, OuterEvent::motions(RawEvent::MotionExpired(hash)));
+		});
+	}
+
+	#[test]
+	fn decided_motions_do_not_expire() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 2, Box::new(proposal)));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+			assert_eq!(CouncilMotions::expiry_of(&hash), None);
+
+			System::set_block_number(101);
+			let events = System::events().len();
+			CouncilMotions::expire_motions(101);
+			assert_eq!(System::events().len(), events);
+			assert_eq!(CouncilMotions::expiries(101), vec![]);
+		});
+	}
+
+	#[test]
+	fn motion_expiry_is_bounded_per_block() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let count = MAX_EXPIRIES_PER_BLOCK as u64 + 2;
+			for value in 0..count {
+				assert_ok!(CouncilMotions::propose(Origin::signed(1), 3, Box::new(set_balance_proposal(value))));
+			}
+
+			System::set_block_number(101);
+			CouncilMotions::expire_motions(101);
+			assert_eq!(CouncilMotions::proposals().len(), 2);
+			assert_eq!(CouncilMotions::expiries(102).len(), 2);
+
+			System::set_block_number(102);
+			CouncilMotions::expire_motions(102);
+			assert_eq!(CouncilMotions::proposals().len(), 0);
+		});
+	}
+
+	#[test]
+	fn motion_deposit_is_returned_when_approved() {
+		with_externalities(&mut new_test_ext(true), || {
+			MOTION_DEPOSIT.with(|d| *d.borrow_mut() = 11);
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_noop!(
+				CouncilMotions::propose(Origin::signed(1), 2, Box::new(proposal.clone())),
+				"proposer's balance too low"
+			);
+			MOTION_DEPOSIT.with(|d| *d.borrow_mut() = 5);
+			assert_ok!(CouncilMotions::propose(Origin::signed(2), 2, Box::new(proposal)));
+			assert_eq!(CouncilMotions::deposit_of(&hash), Some((2, 5)));
+			assert_eq!(Balances::reserved_balance(&2), 5);
+
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hash.clone(), 0, true));
+			assert_eq!(CouncilMotions::deposit_of(&hash), None);
+			assert_eq!(Balances::reserved_balance(&2), 0);
+			assert_eq!(Balances::free_balance(&2), 20);
+		});
+	}
+
+	#[test]
+	fn motion_deposit_is_slashed_when_rejected_without_support() {
+		with_externalities(&mut new_test_ext(true), || {
+			MOTION_DEPOSIT.with(|d| *d.borrow_mut() = 5);
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(2), 3, Box::new(proposal)));
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hash.clone(), 0, false));
+			assert_eq!(CouncilMotions::deposit_of(&hash), None);
+			assert_eq!(Balances::total_balance(&2), 15);
+			assert_eq!(
+				System::events().last().unwrap().event,
+				OuterEvent::motions(RawEvent::DepositSlashed(2, 5))
+			);
+		});
+	}
+
+	#[test]
+	fn motion_deposit_is_settled_once_on_expiry() {
+		with_externalities(&mut new_test_ext(true), || {
+			MOTION_DEPOSIT.with(|d| *d.borrow_mut() = 5);
+			System::set_block_number(1);
+			let supported = set_balance_proposal(42);
+			let supported_hash: H256 = supported.blake2_256().into();
+			let unsupported = set_balance_proposal(43);
+			let unsupported_hash: H256 = unsupported.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(2), 3, Box::new(supported)));
+			assert_ok!(CouncilMotions::propose(Origin::signed(3), 3, Box::new(unsupported)));
+			assert_ok!(CouncilMotions::vote(Origin::signed(1), supported_hash.clone(), 0, true));
+
+			System::set_block_number(101);
+			CouncilMotions::expire_motions(101);
+			assert_eq!(Balances::total_balance(&2), 20);
+			assert_eq!(Balances::reserved_balance(&2), 0);
+			assert_eq!(Balances::total_balance(&3), 25);
+			assert_eq!(CouncilMotions::deposit_of(&unsupported_hash), None);
+
+			// a late close finds nothing left to settle.
+			assert_noop!(CouncilMotions::close(Origin::signed(1), unsupported_hash, 1), "proposal must exist");
+			assert_eq!(Balances::total_balance(&3), 25);
+		});
+	}
+
+	#[test]
+	fn motions_amend_works_before_any_vote() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			let amended = set_balance_proposal(43);
+			let amended_hash: H256 = amended.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_justification(
+				Origin::signed(1), 3, Box::new(proposal), b"typo".to_vec()
+			));
+
+			assert_noop!(
+				CouncilMotions::amend(Origin::signed(2), hash.clone(), Box::new(amended.clone())),
+				"only the proposer may amend a motion"
+			);
+			assert_ok!(CouncilMotions::amend(Origin::signed(1), hash.clone(), Box::new(amended.clone())));
+			assert_eq!(CouncilMotions::proposals(), vec![amended_hash]);
+			assert_eq!(CouncilMotions::proposal_of(&hash), None);
+			assert_eq!(CouncilMotions::proposal_of(&amended_hash), Some(amended));
+			assert_eq!(CouncilMotions::voting(&hash), None);
+			assert_eq!(CouncilMotions::voting(&amended_hash), Some((0, 3, vec![1], vec![])));
+			assert_eq!(CouncilMotions::justification_of(&amended_hash), b"typo".to_vec());
+			assert_eq!(CouncilMotions::expiry_of(&amended_hash), Some(101));
+			assert_eq!(
+				System::events().last().unwrap().event,
+				OuterEvent::motions(RawEvent::MotionAmended(hash, amended_hash))
+			);
+
+			// the amended motion expires in place of the original.
+			System::set_block_number(101);
+			CouncilMotions::expire_motions(101);
+			assert_eq!(CouncilMotions::proposals(), Vec::<H256>::new());
+		});
+	}
+
+	#[test]
+	fn motions_amend_fails_once_voted_on() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose(Origin::signed(1), 3, Box::new(proposal)));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, false));
+			assert_noop!(
+				CouncilMotions::amend(Origin::signed(1), hash, Box::new(set_balance_proposal(43))),
+				"motion already voted on"
+			);
+			assert_noop!(
+				CouncilMotions::amend(Origin::signed(1), H256::default(), Box::new(set_balance_proposal(43))),
+				"proposal must exist"
+			);
+		});
+	}
+
+	#[test]
+	fn motions_executed_at_deadline_are_condoned_only_by_their_supporters() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = Call::Dummy(dummy::Call::act_as_council());
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilMotions::propose_with_deadline(Origin::signed(1), 3, Box::new(proposal.clone()), 5));
+			assert_ok!(CouncilMotions::vote(Origin::signed(2), hash.clone(), 0, true));
+
+			System::set_block_number(5);
+			CouncilMotions::enforce_deadlines(5);
+
+			// two yes votes do not make an origin of the three members the call requires.
+			let events: Vec<_> = System::events().into_iter().map(|r| r.event).rev().take(2).collect();
+			assert_eq!(events, vec![
+				OuterEvent::motions(RawEvent::Executed(hash, false)),
+				OuterEvent::motions(RawEvent::DeadlineReached(hash)),
+			]);
+		});
+	}
 }
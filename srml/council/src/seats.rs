@@ -17,11 +17,12 @@
 //! Council system: Handles the voting in and maintenance of council members.
 
 use rstd::prelude::*;
-use primitives::traits::{Zero, One, StaticLookup};
+use primitives::{Perbill, Permill};
+use primitives::traits::{Zero, One, StaticLookup, Saturating, UniqueSaturatedInto};
 use runtime_io::print;
 use srml_support::{
 	StorageValue, StorageMap, dispatch::Result, decl_storage, decl_event, ensure,
-	traits::{Currency, ReservableCurrency, OnUnbalanced}
+	traits::{Currency, ReservableCurrency, OnUnbalanced, Imbalance, Get}
 };
 use democracy;
 use system::{self, ensure_signed};
@@ -95,6 +96,27 @@ pub trait Trait: democracy::Trait {
 
 	/// Handler for the unbalanced reduction when slashing an invalid reaping attempt.
 	type BadReaper: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+	/// The maximum number of candidates that may be registered at once. This bounds the cost of
+	/// the tally.
+	type MaxCandidates: Get<u32>;
+
+	/// The number of tallies over which the approval stake of a voter who does not refresh their
+	/// approvals decays linearly to nothing. Zero disables the decay.
+	type ApprovalDecayPeriod: Get<VoteIndex>;
+
+	/// The number of most recent slashes kept in each account's slash history.
+	type MaxSlashHistory: Get<u32>;
+
+	/// The fraction of the total issuance which must back the voters for a tally to go ahead.
+	type MinimumTurnout: Get<Permill>;
+
+	/// The bond reserved from an account for as long as it delegates its approval stake.
+	type DelegationBond: Get<BalanceOf<Self>>;
+
+	/// The maximum number of accounts which may delegate directly to any one account. Together
+	/// with the delegation bond, this bounds the cost of resolving delegated stake at the tally.
+	type MaxDelegators: Get<u32>;
 }
 
 decl_module! {
@@ -108,6 +130,54 @@ decl_module! {
 			Self::do_set_approvals(who, votes, index)
 		}
 
+		/// Delegate the sender's approval stake to `to`, whose approvals it will back at the next
+		/// tally. `to` may itself have delegated, and others may have delegated to the sender, up
+		/// to `MaxDelegationDepth` hops in all. Delegators are not voters and pay no voting bond,
+		/// but a `DelegationBond` instead, held until they undelegate.
+		fn delegate(origin, to: T::AccountId) {
+			let who = ensure_signed(origin)?;
+			ensure!(!<LastActiveOf<T>>::exists(&who), "voters may not delegate");
+			let delegators = Self::delegators_of(&to);
+			ensure!(
+				delegators.contains(&who) || (delegators.len() as u32) < T::MaxDelegators::get(),
+				"too many delegators"
+			);
+
+			// the chains already ending at the sender are extended by this delegation.
+			let mut depth = Self::delegation_depth_to(&who) + 1;
+			let mut next = to.clone();
+			loop {
+				ensure!(next != who, "delegation would form a cycle");
+				ensure!(depth <= Self::max_delegation_depth(), "delegation chain too deep");
+				match Self::delegate_of(&next) {
+					Some(d) => {
+						next = d;
+						depth += 1;
+					}
+					None => break,
+				}
+			}
+
+			match <Delegations<T>>::get(&who) {
+				Some(old) => <DelegatorsOf<T>>::mutate(&old, |d| d.retain(|a| a != &who)),
+				// NOTE: This must be last as it has side-effects.
+				None => T::Currency::reserve(&who, T::DelegationBond::get())
+					.map_err(|_| "delegator has not enough funds for the bond")?,
+			}
+			<Delegations<T>>::insert(&who, &to);
+			<DelegatorsOf<T>>::mutate(&to, |d| d.push(who.clone()));
+			Self::deposit_event(RawEvent::Delegated(who, to));
+		}
+
+		/// Stop delegating the sender's approval stake.
+		fn undelegate(origin) {
+			let who = ensure_signed(origin)?;
+			let to = <Delegations<T>>::take(&who).ok_or("not delegating")?;
+			<DelegatorsOf<T>>::mutate(&to, |d| d.retain(|a| a != &who));
+			T::Currency::unreserve(&who, T::DelegationBond::get());
+			Self::deposit_event(RawEvent::Undelegated(who, to));
+		}
+
 		/// Set candidate approvals from a proxy. Approval slots stay valid as long as candidates in those slots
 		/// are registered.
 		fn proxy_set_approvals(origin, votes: Vec<bool>, #[compact] index: VoteIndex) -> Result {
@@ -115,6 +185,20 @@ decl_module! {
 			Self::do_set_approvals(who, votes, index)
 		}
 
+		/// Remove an inactive voter on behalf of the reporting voter whose democracy proxy the
+		/// sender is. The reporter's bond is at stake, exactly as with `reap_inactive_voter`.
+		fn proxy_reap_inactive_voter(
+			origin,
+			#[compact] reporter_index: u32,
+			who: <T::Lookup as StaticLookup>::Source,
+			#[compact] who_index: u32,
+			#[compact] assumed_vote_index: VoteIndex
+		) {
+			let reporter = <democracy::Module<T>>::proxy(ensure_signed(origin)?).ok_or("not a proxy")?;
+			let who = T::Lookup::lookup(who)?;
+			Self::do_reap_inactive_voter(reporter, reporter_index, who, who_index, assumed_vote_index)?;
+		}
+
 		/// Remove a voter. For it not to be a bond-consuming no-op, all approved candidate indices
 		/// must now be either unregistered or registered to a candidate that registered the slot after
 		/// the voter gave their last approval set.
@@ -128,59 +212,21 @@ decl_module! {
 			#[compact] assumed_vote_index: VoteIndex
 		) {
 			let reporter = ensure_signed(origin)?;
-
 			let who = T::Lookup::lookup(who)?;
-			ensure!(!Self::presentation_active(), "cannot reap during presentation period");
-			ensure!(Self::voter_last_active(&reporter).is_some(), "reporter must be a voter");
-			let last_active = Self::voter_last_active(&who).ok_or("target for inactivity cleanup must be active")?;
-			ensure!(assumed_vote_index == Self::vote_index(), "vote index not current");
-			ensure!(assumed_vote_index > last_active + Self::inactivity_grace_period(), "cannot reap during grace period");
-			let voters = Self::voters();
-			let reporter_index = reporter_index as usize;
-			let who_index = who_index as usize;
-			ensure!(reporter_index < voters.len() && voters[reporter_index] == reporter, "bad reporter index");
-			ensure!(who_index < voters.len() && voters[who_index] == who, "bad target index");
-
-			// will definitely kill one of signed or who now.
-
-			let valid = !Self::approvals_of(&who).iter()
-				.zip(Self::candidates().iter())
-				.any(|(&appr, addr)|
-					 appr &&
-					 *addr != T::AccountId::default() &&
-					 Self::candidate_reg_info(addr).map_or(false, |x| x.0 <= last_active)/*defensive only: all items in candidates list are registered*/
-				);
-
-			Self::remove_voter(
-				if valid { &who } else { &reporter },
-				if valid { who_index } else { reporter_index },
-				voters
-			);
-			if valid {
-				// This only fails if `reporter` doesn't exist, which it clearly must do since its the origin.
-				// Still, it's no more harmful to propagate any error at this point.
-				T::Currency::repatriate_reserved(&who, &reporter, Self::voting_bond())?;
-				Self::deposit_event(RawEvent::VoterReaped(who, reporter));
-			} else {
-				let imbalance = T::Currency::slash_reserved(&reporter, Self::voting_bond()).0;
-				T::BadReaper::on_unbalanced(imbalance);
-				Self::deposit_event(RawEvent::BadReaperSlashed(reporter));
-			}
+			Self::do_reap_inactive_voter(reporter, reporter_index, who, who_index, assumed_vote_index)?;
 		}
 
 		/// Remove a voter. All votes are cancelled and the voter deposit is returned.
 		fn retract_voter(origin, #[compact] index: u32) {
 			let who = ensure_signed(origin)?;
+			Self::do_retract_voter(who, index)?;
+		}
 
-			ensure!(!Self::presentation_active(), "cannot retract when presenting");
-			ensure!(<LastActiveOf<T>>::exists(&who), "cannot retract non-voter");
-			let voters = Self::voters();
-			let index = index as usize;
-			ensure!(index < voters.len(), "retraction index invalid");
-			ensure!(voters[index] == who, "retraction index mismatch");
-
-			Self::remove_voter(&who, index, voters);
-			T::Currency::unreserve(&who, Self::voting_bond());
+		/// Remove the voter whose democracy proxy the sender is. All votes are cancelled and the
+		/// voter deposit is returned to the voter.
+		fn proxy_retract_voter(origin, #[compact] index: u32) {
+			let who = <democracy::Module<T>>::proxy(ensure_signed(origin)?).ok_or("not a proxy")?;
+			Self::do_retract_voter(who, index)?;
 		}
 
 		/// Submit oneself for candidacy.
@@ -190,11 +236,14 @@ decl_module! {
 			let who = ensure_signed(origin)?;
 
 			ensure!(!Self::is_a_candidate(&who), "duplicate candidate submission");
+			ensure!(Self::candidate_count() < T::MaxCandidates::get(), "too many candidates");
+			let retracted = Self::retracted_slots();
+			ensure!(!retracted.contains(&slot), "slot retracted until the next tally");
 			let slot = slot as usize;
 			let count = Self::candidate_count() as usize;
 			let candidates = Self::candidates();
 			ensure!(
-				(slot == count && count == candidates.len()) ||
+				(slot == candidates.len() && count + retracted.len() == candidates.len()) ||
 					(slot < candidates.len() && candidates[slot] == T::AccountId::default()),
 				"invalid candidate slot"
 			);
@@ -213,6 +262,75 @@ decl_module! {
 			<CandidateCount<T>>::put(count as u32 + 1);
 		}
 
+		/// Withdraw the sender's candidacy from slot `index`, returning their candidacy bond. Only
+		/// possible before the next tally begins.
+		fn retract_candidacy(origin, #[compact] index: u32) {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Self::presentation_active(), "cannot retract candidacy during presentation");
+			if let Some(tally) = Self::next_tally() {
+				ensure!(<system::Module<T>>::block_number() < tally, "cannot retract candidacy once the tally is due");
+			}
+			let candidates = Self::candidates();
+			let index = index as usize;
+			ensure!(index < candidates.len() && candidates[index] == who, "candidate not in given slot");
+			ensure!(
+				Self::retracted_slots().len() < T::MaxCandidates::get() as usize,
+				"too many candidacies retracted since the last tally"
+			);
+
+			Self::withdraw_candidacy(&who, index);
+			Self::deposit_event(RawEvent::CandidacyRetracted(who));
+		}
+
+		/// Give up the sender's council seat. The best runner-up of the last tally who is still a
+		/// candidate takes it over for the rest of its term; failing one, it stays empty until the
+		/// next tally. Seated councillors hold no bond, so there is none to return.
+		fn resign(origin) {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Self::presentation_active(), "cannot resign during presentation");
+			let mut council = Self::active_council();
+			let position = council.iter().position(|&(ref a, _)| a == &who).ok_or("not on council")?;
+			let (_, expiry) = council.remove(position);
+			<MotionParticipation<T>>::remove(&who);
+			<BackingStakeOf<T>>::remove(&who);
+			Self::deposit_event(RawEvent::CouncillorResigned(who));
+
+			let mut runners_up: Vec<(T::AccountId, BalanceOf<T>, u32)> = Self::runners_up().into_iter()
+				.filter(|(r, _)| !council.iter().any(|&(ref a, _)| a == r))
+				.filter_map(|(r, stake)| Self::candidate_reg_info(&r).map(|(_, slot)| (r, stake, slot)))
+				.collect();
+			if !runners_up.is_empty() {
+				let (successor, stake, slot) = runners_up.remove(0);
+				Self::withdraw_candidacy(&successor, slot as usize);
+				<MotionParticipation<T>>::remove(&successor);
+				<BackingStakeOf<T>>::insert(&successor, stake);
+				council.push((successor.clone(), expiry));
+				council.sort_by_key(|&(_, expiry)| expiry);
+				Self::deposit_event(RawEvent::SeatFilled(successor));
+			}
+			<RunnersUp<T>>::put(runners_up.into_iter().map(|(r, stake, _)| (r, stake)).collect::<Vec<_>>());
+			<ActiveCouncil<T>>::put(council);
+		}
+
+		/// Endorse the registered `candidate` as a sitting councillor. Endorsements are for voters'
+		/// information only and have no bearing on the tally.
+		fn endorse_candidate(origin, candidate: <T::Lookup as StaticLookup>::Source) {
+			let who = ensure_signed(origin)?;
+			let candidate = T::Lookup::lookup(candidate)?;
+
+			ensure!(Self::active_council().iter().any(|&(ref a, _)| a == &who), "endorser not on council");
+			ensure!(who != candidate, "cannot endorse oneself");
+			ensure!(Self::is_a_candidate(&candidate), "endorsed account must be a candidate");
+			let mut endorsements = Self::candidate_endorsements(&candidate);
+			ensure!(!endorsements.contains(&who), "candidate already endorsed");
+
+			endorsements.push(who.clone());
+			<CandidateEndorsements<T>>::insert(&candidate, endorsements);
+			Self::deposit_event(RawEvent::CandidateEndorsed(who, candidate));
+		}
+
 		/// Claim that `signed` is one of the top Self::carry_count() + current_vote().1 candidates.
 		/// Only works if the `block_number >= current_vote().0` and `< current_vote().0 + presentation_duration()``
 		/// `signed` should have at least
@@ -263,6 +381,7 @@ decl_module! {
 				// we can rest assured it will be Ok since we checked `can_slash` earlier; still
 				// better safe than sorry.
 				let imbalance = T::Currency::slash(&who, bad_presentation_punishment).0;
+				Self::note_slash(&who, imbalance.peek());
 				T::BadPresentation::on_unbalanced(imbalance);
 				Err(if dupe { "duplicate presentation" } else { "incorrect total" })
 			}
@@ -275,6 +394,12 @@ decl_module! {
 			<DesiredSeats<T>>::put(count);
 		}
 
+		/// Set the greatest number of hops a chain of delegations may have. Chains already longer
+		/// are left as they are.
+		fn set_max_delegation_depth(#[compact] depth: u32) {
+			<MaxDelegationDepth<T>>::put(depth);
+		}
+
 		/// Remove a particular member. A tally will happen instantly (if not already in a presentation
 		/// period) to fill the seat if removal means that the desired members are not met.
 		/// This is effective immediately.
@@ -285,6 +410,8 @@ decl_module! {
 				.filter(|i| i.0 != who)
 				.collect();
 			<ActiveCouncil<T>>::put(new_council);
+			<MotionParticipation<T>>::remove(&who);
+			<BackingStakeOf<T>>::remove(&who);
 		}
 
 		/// Set the presentation duration. If there is currently a vote being presented for, will
@@ -299,6 +426,13 @@ decl_module! {
 			<TermDuration<T>>::put(count);
 		}
 
+		/// Start a tally now regardless of the turnout, e.g. when a council left empty by low
+		/// turnout could otherwise never be elected.
+		fn force_tally() {
+			ensure!(!Self::presentation_active(), "cannot force a tally during presentation");
+			Self::start_tally(false);
+		}
+
 		fn on_finalize(n: T::BlockNumber) {
 			if let Err(e) = Self::end_block(n) {
 				print("Guru meditation");
@@ -340,6 +474,24 @@ decl_storage! {
 		pub ActiveCouncil get(active_council) config(): Vec<(T::AccountId, T::BlockNumber)>;
 		/// The total number of votes that have happened or are in progress.
 		pub VoteCount get(vote_index): VoteIndex;
+		/// The number of motions opened and the number of those voted on by a councillor during their
+		/// current term.
+		pub MotionParticipation get(motion_participation): map T::AccountId => (u32, u32);
+		/// The approval stake with which a councillor was elected for their current term.
+		pub BackingStakeOf get(backing_stake_of): map T::AccountId => BalanceOf<T>;
+		/// Each member elected at the last tally with their approval stake and number of backers.
+		pub LastElectionBreakdown get(last_election_breakdown): Vec<(T::AccountId, BalanceOf<T>, u32)>;
+		/// The most recent slashes an account has incurred, for bad presentations or reaps, with
+		/// the block they happened at. Oldest first; at most `MaxSlashHistory` are kept.
+		pub SlashHistory get(slash_history): map T::AccountId => Vec<(T::BlockNumber, BalanceOf<T>)>;
+		/// The greatest number of hops a chain of delegations may have, whether of approval stake
+		/// or of council votes.
+		pub MaxDelegationDepth get(max_delegation_depth) config(): u32;
+		/// The account to which an account has delegated its approval stake.
+		pub Delegations get(delegate_of): map T::AccountId => Option<T::AccountId>;
+		/// The accounts which have delegated their approval stake directly to an account, at most
+		/// `MaxDelegators` of them.
+		pub DelegatorsOf get(delegators_of): map T::AccountId => Vec<T::AccountId>;
 
 		// persistent state (always relevant, changes constantly)
 		/// A list of votes for each voter, respecting the last cleared vote index that this voter was
@@ -354,7 +506,15 @@ decl_storage! {
 		pub Voters get(voters): Vec<T::AccountId>;
 		/// The present candidate list.
 		pub Candidates get(candidates): Vec<T::AccountId>; // has holes
+		/// The runners-up of the last tally, carried over as candidates, with their approval stake,
+		/// best first.
+		pub RunnersUp get(runners_up): Vec<(T::AccountId, BalanceOf<T>)>;
+		/// The councillors who have endorsed a candidate.
+		pub CandidateEndorsements get(candidate_endorsements): map T::AccountId => Vec<T::AccountId>;
 		pub CandidateCount get(candidate_count): u32;
+		/// The candidate slots vacated since the last tally. They stay empty until the next one, lest
+		/// the approvals given to their previous holder count for a new one.
+		pub RetractedSlots get(retracted_slots): Vec<u32>;
 
 		// temporary state (only relevant during finalization/presentation)
 		/// The accounts holding the seats that will become free on the next tally.
@@ -374,8 +534,23 @@ decl_event!(
 		BadReaperSlashed(AccountId),
 		/// A tally (for approval votes of council seat(s)) has started.
 		TallyStarted(u32),
+		/// A tally for the given number of seats was deferred since too little stake backed the
+		/// voters.
+		TallyDeferredLowTurnout(u32),
 		/// A tally (for approval votes of council seat(s)) has ended (with one or more new members).
 		TallyFinalized(Vec<AccountId>, Vec<AccountId>),
+		/// A candidate withdrew their candidacy before the tally.
+		CandidacyRetracted(AccountId),
+		/// A councillor (first) endorsed a candidate (second).
+		CandidateEndorsed(AccountId, AccountId),
+		/// A councillor gave up their seat.
+		CouncillorResigned(AccountId),
+		/// A runner-up was seated in place of a councillor who resigned.
+		SeatFilled(AccountId),
+		/// An account (first) delegated its approval stake to another (second).
+		Delegated(AccountId, AccountId),
+		/// An account (first) stopped delegating its approval stake to another (second).
+		Undelegated(AccountId, AccountId),
 	}
 );
 
@@ -398,6 +573,211 @@ impl<T: Trait> Module<T> {
 		(n + voting_period - One::one()) / voting_period * voting_period
 	}
 
+	/// The fraction of motions opened during the current term of `who` which they have voted on.
+	/// `None` if `who` is not on the council or no motion has been opened during their term yet.
+	pub fn participation_rate(who: &T::AccountId) -> Option<Perbill> {
+		if !Self::active_council().iter().any(|&(ref a, _)| a == who) {
+			return None;
+		}
+		let (opened, voted) = Self::motion_participation(who);
+		if opened == 0 {
+			None
+		} else {
+			Some(Perbill::from_parts((voted.min(opened) as u64 * 1_000_000_000 / opened as u64) as u32))
+		}
+	}
+
+	/// Each seated councillor with the block at which their term ends, soonest first.
+	pub fn term_expiries() -> Vec<(T::AccountId, T::BlockNumber)> {
+		let mut expiries = Self::active_council();
+		expiries.sort_by_key(|&(_, expiry)| expiry);
+		expiries
+	}
+
+	/// The approval stake of the lowest-ranked candidate currently presented as winning a seat:
+	/// the stake which must be beaten to be elected. `None` outside of a presentation period or
+	/// while not every seat up for election has a winner presented.
+	pub fn marginal_seat_stake() -> Option<BalanceOf<T>> {
+		let (_, coming, _) = Self::next_finalize()?;
+		let leaderboard = Self::leaderboard()?;
+		let winners: Vec<BalanceOf<T>> = leaderboard.iter()
+			.rev()
+			.map(|&(b, _)| b)
+			.take_while(|b| !b.is_zero())
+			.take(coming as usize)
+			.collect();
+		if coming == 0 || winners.len() < coming as usize {
+			None
+		} else {
+			winners.last().cloned()
+		}
+	}
+
+	/// The 1-based rank of the candidate `who` among all current candidates by the approval stake
+	/// they would be presented with now. Equal stakes are ranked as they would be on the leaderboard
+	/// were the candidates presented in slot order. `None` if `who` is not a candidate.
+	pub fn candidate_rank(who: &T::AccountId) -> Option<u32> {
+		if !Self::is_a_candidate(who) {
+			return None;
+		}
+		Self::standings().iter().position(|&(_, ref c)| c == who).map(|p| p as u32 + 1)
+	}
+
+	/// Every current candidate with the approval stake they would be presented with now and
+	/// whether that currently wins them one of the seats up for election, in seating order.
+	pub fn candidates_with_standings() -> Vec<(T::AccountId, BalanceOf<T>, bool)> {
+		let open_seats = match (Self::next_finalize(), Self::next_tally()) {
+			(Some((_, coming, _)), _) => coming as usize,
+			(None, Some(tally)) => {
+				let retaining = Self::active_council().iter()
+					.filter(|&&(_, expiry)| expiry != tally)
+					.count();
+				(Self::desired_seats() as usize).saturating_sub(retaining)
+			}
+			(None, None) => 0,
+		};
+		Self::standings().into_iter()
+			.enumerate()
+			.map(|(i, (stake, c))| (c, stake, i < open_seats && !stake.is_zero()))
+			.collect()
+	}
+
+	/// Every current candidate with the approval stake they would be presented with now, including
+	/// stake delegated to their backers, in slot order.
+	pub fn candidate_standings() -> Vec<(T::AccountId, BalanceOf<T>)> {
+		Self::candidates().into_iter()
+			.filter(|c| *c != T::AccountId::default())
+			.map(|c| {
+				let stake = Self::backers_of(&c).iter().map(Self::voting_stake_of)
+					.fold(Zero::zero(), |acc: BalanceOf<T>, n| acc.saturating_add(n));
+				(c, stake)
+			})
+			.collect()
+	}
+
+	/// How concentrated the approval stake backing the current candidates is, as their Gini
+	/// coefficient: zero when all have the same stake, approaching one as it all goes to a single
+	/// candidate. Zero if there are fewer than two candidates or no stake at all.
+	pub fn approval_concentration() -> Perbill {
+		let mut stakes = Self::candidate_standings().into_iter()
+			.map(|(_, stake)| stake.unique_saturated_into())
+			.collect::<Vec<u128>>();
+		let n = stakes.len() as u128;
+		let total = stakes.iter().fold(0u128, |t, s| t.saturating_add(*s));
+		if n < 2 || total == 0 {
+			return Perbill::zero();
+		}
+		stakes.sort();
+
+		// sum over the ascending stakes of (2i - n - 1) * stake, for 1-based i.
+		let (mut above, mut below) = (0u128, 0u128);
+		for (i, stake) in stakes.into_iter().enumerate() {
+			let weight = 2 * (i as u128 + 1);
+			if weight > n + 1 {
+				above = above.saturating_add((weight - n - 1).saturating_mul(stake));
+			} else {
+				below = below.saturating_add((n + 1 - weight).saturating_mul(stake));
+			}
+		}
+		let mut numerator = above.saturating_sub(below);
+		let mut denominator = n.saturating_mul(total);
+		while denominator > u64::max_value() as u128 {
+			numerator >>= 1;
+			denominator >>= 1;
+		}
+		Perbill::from_parts((numerator * 1_000_000_000 / denominator) as u32)
+	}
+
+	/// The current candidates with the approval stake they would be presented with now, highest
+	/// first. Equal stakes are ordered as they would be on the leaderboard were the candidates
+	/// presented in slot order.
+	fn standings() -> Vec<(BalanceOf<T>, T::AccountId)> {
+		let mut standings: Vec<(BalanceOf<T>, T::AccountId)> = Self::candidate_standings().into_iter()
+			.map(|(c, stake)| (stake, c))
+			.collect();
+		standings.sort_by_key(|&(stake, _)| stake);
+		standings.reverse();
+		standings
+	}
+
+	/// The stake with which the approvals of the voter `who` count at the current tally: their
+	/// total balance, decayed by the number of tallies since they last set their approvals.
+	pub fn approval_stake_of(who: &T::AccountId) -> BalanceOf<T> {
+		let stake = T::Currency::total_balance(who);
+		let period = T::ApprovalDecayPeriod::get();
+		if period.is_zero() {
+			return stake;
+		}
+		let last_active = Self::voter_last_active(who).unwrap_or_else(Self::vote_index);
+		let inactive = Self::vote_index().saturating_sub(last_active);
+		if inactive >= period {
+			return Zero::zero();
+		}
+		let remaining = (period - inactive) as u64 * 1_000_000_000 / period as u64;
+		Perbill::from_parts(remaining as u32) * stake
+	}
+
+	/// The stake with which the approvals of the voter `who` count at the current tally, including
+	/// all stake delegated to them.
+	fn voting_stake_of(who: &T::AccountId) -> BalanceOf<T> {
+		Self::approval_stake_of(who).saturating_add(Self::delegated_stake_of(who))
+	}
+
+	/// The number of hops in the longest chain of delegations ending at `who`.
+	fn delegation_depth_to(who: &T::AccountId) -> u32 {
+		Self::delegators_of(who).iter()
+			.map(|d| Self::delegation_depth_to(d) + 1)
+			.max()
+			.unwrap_or(0)
+	}
+
+	/// The stake delegated to `who`, directly or through any number of further hops. No chain is
+	/// longer than `MaxDelegationDepth` was when it was made, which bounds the recursion.
+	fn delegated_stake_of(who: &T::AccountId) -> BalanceOf<T> {
+		Self::delegators_of(who).iter().fold(Zero::zero(), |total: BalanceOf<T>, d| {
+			let stake = T::Currency::total_balance(d).saturating_add(Self::delegated_stake_of(d));
+			total.saturating_add(stake)
+		})
+	}
+
+	/// The voters currently approving of the registered candidate `who`.
+	fn backers_of(who: &T::AccountId) -> Vec<T::AccountId> {
+		let (registered_since, candidate_index) = match Self::candidate_reg_info(who) {
+			Some(info) => info,
+			None => return Vec::new(),
+		};
+		Self::voters().into_iter()
+			.filter(|voter| match Self::voter_last_active(voter) {
+				Some(b) if b >= registered_since =>
+					Self::approvals_of(voter).get(candidate_index as usize).cloned().unwrap_or(false),
+				_ => false,
+			})
+			.collect()
+	}
+
+	/// The number of voters currently approving of the registered candidate `who`.
+	fn backer_count(who: &T::AccountId) -> u32 {
+		Self::backers_of(who).len() as u32
+	}
+
+	/// The total approval stake which elected the current council.
+	pub fn council_backing_stake() -> BalanceOf<T> {
+		Self::active_council().iter()
+			.fold(Zero::zero(), |acc: BalanceOf<T>, &(ref who, _)| acc.saturating_add(Self::backing_stake_of(who)))
+	}
+
+	/// Note that a motion has been opened; every current councillor is now expected to vote on it.
+	pub(crate) fn note_motion_opened() {
+		for (who, _) in Self::active_council() {
+			<MotionParticipation<T>>::mutate(&who, |p| p.0 += 1);
+		}
+	}
+
+	/// Note that `who` has cast their first vote on an open motion.
+	pub(crate) fn note_motion_voted(who: &T::AccountId) {
+		<MotionParticipation<T>>::mutate(who, |p| p.1 += 1);
+	}
+
 	/// The block number on which the tally for the next election will happen. `None` only if the
 	/// desired seats of the council is zero.
 	pub fn next_tally() -> Option<T::BlockNumber> {
@@ -428,13 +808,23 @@ impl<T: Trait> Module<T> {
 		}
 	}
 
+	/// The block number on which the winners of the current election, or failing one the next,
+	/// will be seated: the end of its presentation period. `None` only if the desired seats of
+	/// the council is zero.
+	pub fn seating_block() -> Option<T::BlockNumber> {
+		match Self::next_finalize() {
+			Some((tally_end, _, _)) => Some(tally_end),
+			None => Self::next_tally().map(|tally| tally + Self::presentation_duration()),
+		}
+	}
+
 	// Private
 	/// Check there's nothing to do this block
 	fn end_block(block_number: T::BlockNumber) -> Result {
 		if (block_number % Self::voting_period()).is_zero() {
 			if let Some(number) = Self::next_tally() {
 				if block_number == number {
-					Self::start_tally();
+					Self::start_tally(true);
 				}
 			}
 		}
@@ -446,6 +836,35 @@ impl<T: Trait> Module<T> {
 		Ok(())
 	}
 
+	/// Record in the slash history of `who` that they have just been slashed by `amount`.
+	fn note_slash(who: &T::AccountId, amount: BalanceOf<T>) {
+		let max = T::MaxSlashHistory::get() as usize;
+		if max == 0 {
+			return;
+		}
+		let now = <system::Module<T>>::block_number();
+		<SlashHistory<T>>::mutate(who, |history| {
+			let excess = (history.len() + 1).saturating_sub(max);
+			history.drain(..excess);
+			history.push((now, amount));
+		});
+	}
+
+	/// Remove the candidate `who` from slot `index` of the candidate list, returning their
+	/// candidacy bond.
+	fn withdraw_candidacy(who: &T::AccountId, index: usize) {
+		let mut candidates = Self::candidates();
+		// the hole stays, however trailing, until the tally: approvals given to `who` in this
+		// vote index would otherwise count for the next candidate in the slot.
+		candidates[index] = T::AccountId::default();
+		<Candidates<T>>::put(candidates);
+		<RetractedSlots<T>>::mutate(|r| r.push(index as u32));
+		<CandidateCount<T>>::mutate(|c| *c -= 1);
+		<RegisterInfoOf<T>>::remove(who);
+		<CandidateEndorsements<T>>::remove(who);
+		T::Currency::unreserve(who, Self::candidacy_bond());
+	}
+
 	/// Remove a voter from the system. Trusts that Self::voters()[index] != voter.
 	fn remove_voter(voter: &T::AccountId, index: usize, mut voters: Vec<T::AccountId>) {
 		<Voters<T>>::put({ voters.swap_remove(index); voters });
@@ -453,6 +872,68 @@ impl<T: Trait> Module<T> {
 		<LastActiveOf<T>>::remove(voter);
 	}
 
+	// Actually do the reaping.
+	fn do_reap_inactive_voter(
+		reporter: T::AccountId,
+		reporter_index: u32,
+		who: T::AccountId,
+		who_index: u32,
+		assumed_vote_index: VoteIndex,
+	) -> Result {
+		ensure!(!Self::presentation_active(), "cannot reap during presentation period");
+		ensure!(Self::voter_last_active(&reporter).is_some(), "reporter must be a voter");
+		let last_active = Self::voter_last_active(&who).ok_or("target for inactivity cleanup must be active")?;
+		ensure!(assumed_vote_index == Self::vote_index(), "vote index not current");
+		ensure!(assumed_vote_index > last_active + Self::inactivity_grace_period(), "cannot reap during grace period");
+		let voters = Self::voters();
+		let reporter_index = reporter_index as usize;
+		let who_index = who_index as usize;
+		ensure!(reporter_index < voters.len() && voters[reporter_index] == reporter, "bad reporter index");
+		ensure!(who_index < voters.len() && voters[who_index] == who, "bad target index");
+
+		// will definitely kill one of signed or who now.
+
+		let valid = !Self::approvals_of(&who).iter()
+			.zip(Self::candidates().iter())
+			.any(|(&appr, addr)|
+				 appr &&
+				 *addr != T::AccountId::default() &&
+				 Self::candidate_reg_info(addr).map_or(false, |x| x.0 <= last_active)/*defensive only: all items in candidates list are registered*/
+			);
+
+		Self::remove_voter(
+			if valid { &who } else { &reporter },
+			if valid { who_index } else { reporter_index },
+			voters
+		);
+		if valid {
+			// This only fails if `reporter` doesn't exist, which it clearly must do since its the origin.
+			// Still, it's no more harmful to propagate any error at this point.
+			T::Currency::repatriate_reserved(&who, &reporter, Self::voting_bond())?;
+			Self::deposit_event(RawEvent::VoterReaped(who, reporter));
+		} else {
+			let imbalance = T::Currency::slash_reserved(&reporter, Self::voting_bond()).0;
+			Self::note_slash(&reporter, imbalance.peek());
+			T::BadReaper::on_unbalanced(imbalance);
+			Self::deposit_event(RawEvent::BadReaperSlashed(reporter));
+		}
+		Ok(())
+	}
+
+	// Actually do the retracting.
+	fn do_retract_voter(who: T::AccountId, index: u32) -> Result {
+		ensure!(!Self::presentation_active(), "cannot retract when presenting");
+		ensure!(<LastActiveOf<T>>::exists(&who), "cannot retract non-voter");
+		let voters = Self::voters();
+		let index = index as usize;
+		ensure!(index < voters.len(), "retraction index invalid");
+		ensure!(voters[index] == who, "retraction index mismatch");
+
+		Self::remove_voter(&who, index, voters);
+		T::Currency::unreserve(&who, Self::voting_bond());
+		Ok(())
+	}
+
 	// Actually do the voting.
 	fn do_set_approvals(who: T::AccountId, votes: Vec<bool>, index: VoteIndex) -> Result {
 		let candidates = Self::candidates();
@@ -464,6 +945,7 @@ impl<T: Trait> Module<T> {
 		// since otherwise an attacker may be able to submit a very long list of `votes` that far exceeds
 		// the amount of candidates and waste more computation than a reasonable voting bond would cover.
 		ensure!(candidates.len() >= votes.len(), "amount of candidate approval votes cannot exceed amount of candidates");
+		ensure!(!<Delegations<T>>::exists(&who), "delegators may not vote");
 
 		if !<LastActiveOf<T>>::exists(&who) {
 			// not yet a voter - deduct bond.
@@ -479,7 +961,11 @@ impl<T: Trait> Module<T> {
 	}
 
 	/// Close the voting, snapshot the staking and the number of seats that are actually up for grabs.
-	fn start_tally() {
+	///
+	/// If `check_turnout` and the voters are backed by less than `MinimumTurnout` of the total
+	/// issuance, the tally is instead deferred to the next voting period, with the terms of
+	/// expiring councillors extended until then.
+	fn start_tally(check_turnout: bool) {
 		let active_council = Self::active_council();
 		let desired_seats = Self::desired_seats() as usize;
 		let number = <system::Module<T>>::block_number();
@@ -487,10 +973,22 @@ impl<T: Trait> Module<T> {
 		let retaining_seats = active_council.len() - expiring.len();
 		if retaining_seats < desired_seats {
 			let empty_seats = desired_seats - retaining_seats;
-			<NextFinalize<T>>::put((number + Self::presentation_duration(), empty_seats as u32, expiring));
-
 			let voters = Self::voters();
-			let votes = voters.iter().map(T::Currency::total_balance).collect::<Vec<_>>();
+			let votes = voters.iter().map(Self::voting_stake_of).collect::<Vec<_>>();
+
+			let turnout = votes.iter().fold(BalanceOf::<T>::zero(), |t, &v| t.saturating_add(v));
+			if check_turnout && turnout < T::MinimumTurnout::get() * T::Currency::total_issuance() {
+				let deferred_to = Self::next_vote_from(number + One::one());
+				let mut council: Vec<_> = active_council.into_iter()
+					.map(|(a, expiry)| if expiry == number { (a, deferred_to) } else { (a, expiry) })
+					.collect();
+				council.sort_by_key(|&(_, expiry)| expiry);
+				<ActiveCouncil<T>>::put(council);
+				Self::deposit_event(RawEvent::TallyDeferredLowTurnout(empty_seats as u32));
+				return;
+			}
+
+			<NextFinalize<T>>::put((number + Self::presentation_duration(), empty_seats as u32, expiring));
 			<SnapshotedStakes<T>>::put(votes);
 
 			// initialize leaderboard.
@@ -518,12 +1016,27 @@ impl<T: Trait> Module<T> {
 			.rev()
 			.take_while(|&&(b, _)| !b.is_zero())
 			.take(coming as usize)
+			.inspect(|&&(b, ref a)| <BackingStakeOf<T>>::insert(a, b))
 			.map(|(_, a)| a)
 			.cloned()
 			.inspect(|a| {T::Currency::unreserve(a, candidacy_bond);})
 			.collect();
 		let active_council = Self::active_council();
-		let outgoing = active_council.iter().take(expiring.len()).map(|a| a.0.clone()).collect();
+		let outgoing: Vec<T::AccountId> = active_council.iter().take(expiring.len()).map(|a| a.0.clone()).collect();
+
+		<LastElectionBreakdown<T>>::put(
+			incoming.iter()
+				.map(|a| (a.clone(), Self::backing_stake_of(a), Self::backer_count(a)))
+				.collect::<Vec<_>>()
+		);
+
+		// both leaving and newly seated members start over with a clean participation record.
+		for who in outgoing.iter().chain(incoming.iter()) {
+			<MotionParticipation<T>>::remove(who);
+		}
+		for who in outgoing.iter().filter(|a| !incoming.contains(a)) {
+			<BackingStakeOf<T>>::remove(who);
+		}
 
 		// set the new council.
 		let mut new_council: Vec<_> = active_council
@@ -541,16 +1054,20 @@ impl<T: Trait> Module<T> {
 			.rev()
 			.take_while(|&(b, _)| !b.is_zero())
 			.skip(coming as usize)
-			.filter_map(|(_, a)| Self::candidate_reg_info(&a).map(|i| (a, i.1)));
+			.filter_map(|(b, a)| Self::candidate_reg_info(&a).map(|i| (a, b, i.1)));
 		let mut count = 0u32;
-		for (address, slot) in runners_up {
+		let mut carried = Vec::new();
+		for (address, stake, slot) in runners_up {
+			carried.push((address.clone(), stake));
 			new_candidates[slot as usize] = address;
 			count += 1;
 		}
+		<RunnersUp<T>>::put(carried);
 		for (old, new) in candidates.iter().zip(new_candidates.iter()) {
 			if old != new {
 				// removed - kill it
 				<RegisterInfoOf<T>>::remove(old);
+				<CandidateEndorsements<T>>::remove(old);
 			}
 		}
 		// discard any superfluous slots.
@@ -562,6 +1079,7 @@ impl<T: Trait> Module<T> {
 
 		<Candidates<T>>::put(new_candidates);
 		<CandidateCount<T>>::put(count);
+		<RetractedSlots<T>>::kill();
 		<VoteCount<T>>::put(Self::vote_index() + 1);
 		Ok(())
 	}
@@ -571,7 +1089,8 @@ impl<T: Trait> Module<T> {
 mod tests {
 	use super::*;
 	use crate::tests::*;
-	use srml_support::{assert_ok, assert_noop, assert_err};
+	use crate::tests::Call;
+	use srml_support::{Hashable, assert_ok, assert_noop, assert_err};
 
 	#[test]
 	fn params_should_work() {
@@ -1444,4 +1963,709 @@ mod tests {
 			assert_eq!(Council::candidate_reg_info(4), Some((0, 3)));
 		});
 	}
+
+	#[test]
+	fn participation_rate_should_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_eq!(Council::participation_rate(&1), None);
+
+			let hashes: Vec<H256> = (0..4u64).map(|i| {
+				let proposal = Call::Balances(balances::Call::set_balance(42, (42 + i).into(), 0));
+				assert_ok!(CouncilMotions::propose(Origin::signed(2), 3, Box::new(proposal.clone())));
+				proposal.blake2_256().into()
+			}).collect();
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hashes[0], 0, true));
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hashes[1], 1, true));
+			// changing a vote does not count as participating twice.
+			assert_ok!(CouncilMotions::vote(Origin::signed(3), hashes[1], 1, false));
+
+			assert_eq!(Council::participation_rate(&1), Some(Perbill::zero()));
+			assert_eq!(Council::participation_rate(&2), Some(Perbill::one()));
+			assert_eq!(Council::participation_rate(&3), Some(Perbill::from_percent(50)));
+			assert_eq!(Council::participation_rate(&4), None);
+		});
+	}
+
+	#[test]
+	fn candidacy_beyond_max_candidates_should_not_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(1), 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![false, true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, false, true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 3));
+			assert_ok!(Council::set_approvals(Origin::signed(4), vec![false, false, false, true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 4));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, false, false, false, true], 0));
+			assert_eq!(Council::candidate_count(), 5);
+			assert_noop!(Council::submit_candidacy(Origin::signed(6), 5), "too many candidates");
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 1, 60, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 3, 30, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 4, 40, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 50, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			// the elected and the discarded candidates have freed up their slots.
+			assert_eq!(Council::candidate_count(), 2);
+			assert_ok!(Council::submit_candidacy(Origin::signed(6), 0));
+			assert_eq!(Council::candidates(), vec![6, 0, 3, 4]);
+		});
+	}
+
+	#[test]
+	fn council_backing_stake_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::council_backing_stake(), 0);
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 80, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 110, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			assert_eq!(Council::active_council(), vec![(5, 11), (2, 11)]);
+			assert_eq!(Council::backing_stake_of(2), 80);
+			assert_eq!(Council::backing_stake_of(5), 110);
+			assert_eq!(Council::council_backing_stake(), 190);
+
+			assert_ok!(Council::remove_member(2));
+			assert_eq!(Council::council_backing_stake(), 110);
+		});
+	}
+
+	#[test]
+	fn term_expiries_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(1), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, false, true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 1, 60, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 110, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 3, 30, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::term_expiries(), vec![(5, 11), (1, 11)]);
+
+			System::set_block_number(8);
+			assert_ok!(Council::set_desired_seats(3));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(10);
+			assert_ok!(Council::present_winner(Origin::signed(4), 3, 30, 1));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::term_expiries(), vec![(5, 11), (1, 11), (3, 15)]);
+
+			<ActiveCouncil<Test>>::put(vec![(3, 15), (1, 11)]);
+			assert_eq!(Council::term_expiries(), vec![(1, 11), (3, 15)]);
+		});
+	}
+
+	#[test]
+	fn last_election_breakdown_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, true], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::last_election_breakdown(), vec![]);
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 80, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 140, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			assert_eq!(Council::active_council(), vec![(5, 11), (2, 11)]);
+			assert_eq!(Council::last_election_breakdown(), vec![(5, 140, 3), (2, 80, 2)]);
+		});
+	}
+
+	#[test]
+	fn marginal_seat_stake_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, false, true], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, true, false], 0));
+			assert_eq!(Council::marginal_seat_stake(), None);
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::marginal_seat_stake(), None);
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 110, 0));
+			assert_eq!(Council::marginal_seat_stake(), None);
+			assert_ok!(Council::present_winner(Origin::signed(4), 3, 30, 0));
+			assert_eq!(Council::marginal_seat_stake(), Some(30));
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 80, 0));
+			assert_eq!(Council::marginal_seat_stake(), Some(80));
+
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::marginal_seat_stake(), None);
+		});
+	}
+
+	#[test]
+	fn retract_candidacy_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 2));
+			assert_eq!(Balances::free_balance(&3), 21);
+
+			assert_noop!(Council::retract_candidacy(Origin::signed(3), 0), "candidate not in given slot");
+			assert_ok!(Council::retract_candidacy(Origin::signed(3), 1));
+			assert_eq!(Balances::free_balance(&3), 30);
+			assert_eq!(Council::candidates(), vec![2, 0, 4]);
+			assert_eq!(Council::candidate_count(), 2);
+			assert!(!Council::is_a_candidate(&3));
+
+			assert_noop!(Council::submit_candidacy(Origin::signed(5), 1), "slot retracted until the next tally");
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 3));
+			assert_eq!(Council::candidates(), vec![2, 0, 4, 5]);
+
+			assert_ok!(Council::retract_candidacy(Origin::signed(5), 3));
+			assert_ok!(Council::retract_candidacy(Origin::signed(4), 2));
+			assert_eq!(Council::candidates(), vec![2, 0, 0, 0]);
+			assert_eq!(Council::candidate_count(), 1);
+			assert_eq!(Council::retracted_slots(), vec![1, 3, 2]);
+
+			// the holes are discarded, and may be taken again, from the tally on.
+			System::set_block_number(4);
+			assert_ok!(Council::end_block(System::block_number()));
+			System::set_block_number(6);
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::retracted_slots(), Vec::<u32>::new());
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 0));
+		});
+	}
+
+	#[test]
+	fn retracted_slot_should_not_carry_approvals_to_new_candidate() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![false, true], 0));
+			assert_eq!(Council::candidate_standings(), vec![(2, 0), (3, 60)]);
+
+			assert_ok!(Council::retract_candidacy(Origin::signed(3), 1));
+			assert_noop!(Council::submit_candidacy(Origin::signed(4), 1), "slot retracted until the next tally");
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 2));
+			assert_eq!(Council::candidate_standings(), vec![(2, 0), (4, 0)]);
+		});
+	}
+
+	#[test]
+	fn retract_candidacy_once_tally_is_due_should_not_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+
+			System::set_block_number(4);
+			assert_noop!(Council::retract_candidacy(Origin::signed(2), 0), "cannot retract candidacy once the tally is due");
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_noop!(Council::retract_candidacy(Origin::signed(2), 0), "cannot retract candidacy during presentation");
+		});
+	}
+
+	#[test]
+	fn candidate_rank_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 3));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false, false, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, false, true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true, false, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, true, false, false], 0));
+
+			assert_eq!(Council::candidate_rank(&5), Some(1));
+			assert_eq!(Council::candidate_rank(&2), Some(2));
+			assert_eq!(Council::candidate_rank(&3), Some(3));
+			assert_eq!(Council::candidate_rank(&4), Some(4));
+			assert_eq!(Council::candidate_rank(&1), None);
+		});
+	}
+
+	#[test]
+	fn voting_through_democracy_proxy_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Democracy::set_proxy(Origin::signed(5), 7));
+
+			assert_ok!(Council::proxy_set_approvals(Origin::signed(7), vec![true], 0));
+			assert_eq!(Council::voters(), vec![5]);
+			assert_eq!(Council::approvals_of(5), vec![true]);
+			assert_eq!(Council::approvals_of(7), Vec::<bool>::new());
+			assert_eq!(Balances::free_balance(&5), 47);
+
+			assert_noop!(Council::retract_voter(Origin::signed(7), 0), "cannot retract non-voter");
+			assert_ok!(Council::proxy_retract_voter(Origin::signed(7), 0));
+			assert_eq!(Council::voters(), Vec::<u64>::new());
+			assert_eq!(Balances::free_balance(&5), 50);
+
+			assert_ok!(Democracy::remove_proxy(Origin::signed(5), 7));
+			assert_noop!(Council::proxy_set_approvals(Origin::signed(7), vec![true], 0), "not a proxy");
+			assert_noop!(Council::proxy_retract_voter(Origin::signed(7), 0), "not a proxy");
+
+			// naming a voter as one's proxy does not take over their own votes.
+			assert_ok!(Council::set_approvals(Origin::signed(4), vec![true], 0));
+			assert_ok!(Democracy::set_proxy(Origin::signed(5), 4));
+			assert_ok!(Council::set_approvals(Origin::signed(4), vec![false], 0));
+			assert_eq!(Council::voters(), vec![4]);
+			assert_eq!(Council::approvals_of(4), vec![false]);
+			assert_ok!(Council::retract_voter(Origin::signed(4), 0));
+			assert_eq!(Balances::free_balance(&4), 40);
+			assert_eq!(Council::voters(), Vec::<u64>::new());
+		});
+	}
+
+	#[test]
+	fn reaping_through_proxy_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 20, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(8);
+			assert_ok!(Democracy::set_proxy(Origin::signed(5), 7));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 0));
+			assert_ok!(Council::proxy_set_approvals(Origin::signed(7), vec![true], 1));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(10);
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 50, 1));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			assert_ok!(Council::proxy_reap_inactive_voter(Origin::signed(7),
+				(Council::voters().iter().position(|&i| i == 5).unwrap() as u32).into(),
+				2, (Council::voters().iter().position(|&i| i == 2).unwrap() as u32).into(),
+				2
+			));
+
+			assert_eq!(Council::voters(), vec![5]);
+			assert_eq!(Council::approvals_of(2).len(), 0);
+			assert_eq!(Balances::total_balance(&2), 17);
+			assert_eq!(Balances::total_balance(&5), 53);
+			assert_eq!(Balances::total_balance(&7), 0);
+		});
+	}
+
+	#[test]
+	fn approval_stake_decays_with_voter_inactivity() {
+		with_externalities(&mut new_test_ext(false), || {
+			APPROVAL_DECAY_PERIOD.with(|p| *p.borrow_mut() = 2);
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, false], 0));
+			<VoteCount<Test>>::put(1);
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, true], 1));
+			assert_eq!(Council::approval_stake_of(&6), 30);
+			assert_eq!(Council::approval_stake_of(&3), 30);
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::snapshoted_stakes(), vec![30, 30]);
+
+			System::set_block_number(6);
+			assert_eq!(Council::present_winner(Origin::signed(4), 2, 60, 1), Err("incorrect total"));
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 30, 1));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 30, 1));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			assert_eq!(Council::active_council(), vec![(5, 11), (2, 11)]);
+		});
+	}
+
+	#[test]
+	fn fully_decayed_approvals_count_nothing_until_refreshed() {
+		with_externalities(&mut new_test_ext(false), || {
+			APPROVAL_DECAY_PERIOD.with(|p| *p.borrow_mut() = 2);
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, false], 0));
+			<VoteCount<Test>>::put(2);
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, true], 2));
+			assert_eq!(Council::approval_stake_of(&6), 0);
+			assert_eq!(Council::approval_stake_of(&3), 30);
+			assert_eq!(Council::candidate_rank(&5), Some(1));
+
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, false], 2));
+			assert_eq!(Council::approval_stake_of(&6), 60);
+			assert_eq!(Council::candidate_rank(&2), Some(1));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::snapshoted_stakes(), vec![60, 30]);
+		});
+	}
+
+	#[test]
+	fn slash_history_should_record_bad_presentations() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::slash_history(4), vec![]);
+
+			System::set_block_number(5);
+			assert_err!(Council::present_winner(Origin::signed(4), 2, 80, 0), "incorrect total");
+			assert_eq!(Council::slash_history(4), vec![(5, 2)]);
+
+			// only the most recent `MaxSlashHistory` are kept.
+			assert_err!(Council::present_winner(Origin::signed(4), 5, 80, 0), "incorrect total");
+			System::set_block_number(6);
+			assert_err!(Council::present_winner(Origin::signed(4), 2, 70, 0), "incorrect total");
+			assert_eq!(Council::slash_history(4), vec![(5, 2), (6, 2)]);
+			assert_eq!(Balances::total_balance(&4), 34);
+		});
+	}
+
+	#[test]
+	fn candidate_endorsement_should_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(1), 1));
+			assert_eq!(Council::candidate_endorsements(5), vec![]);
+
+			assert_ok!(Council::endorse_candidate(Origin::signed(1), 5));
+			assert_ok!(Council::endorse_candidate(Origin::signed(2), 5));
+			assert_noop!(Council::endorse_candidate(Origin::signed(1), 5), "candidate already endorsed");
+			assert_noop!(Council::endorse_candidate(Origin::signed(1), 1), "cannot endorse oneself");
+			assert_noop!(Council::endorse_candidate(Origin::signed(4), 5), "endorser not on council");
+			assert_noop!(Council::endorse_candidate(Origin::signed(2), 6), "endorsed account must be a candidate");
+			assert_ok!(Council::endorse_candidate(Origin::signed(2), 1));
+
+			assert_eq!(Council::candidate_endorsements(5), vec![1, 2]);
+			assert_eq!(Council::candidate_endorsements(1), vec![2]);
+			assert_eq!(Council::candidate_endorsements(6), vec![]);
+
+			assert_ok!(Council::retract_candidacy(Origin::signed(5), 0));
+			assert_eq!(Council::candidate_endorsements(5), vec![]);
+		});
+	}
+
+	#[test]
+	fn candidates_with_standings_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_eq!(Council::candidates_with_standings(), vec![]);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 3));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, false, true], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true, true, false], 0));
+
+			assert_eq!(
+				Council::candidates_with_standings(),
+				vec![(5, 110, true), (2, 80, true), (3, 30, false), (4, 0, false)]
+			);
+		});
+	}
+
+	#[test]
+	fn resigning_should_seat_runners_up() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(1), 0));
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![false, true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![false, false, true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 3));
+			assert_ok!(Council::set_approvals(Origin::signed(4), vec![false, false, false, true], 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 4));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, false, false, false, true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 1, 60, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 3, 30, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 4, 40, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 50, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::active_council(), vec![(1, 11), (5, 11)]);
+			assert_eq!(Council::runners_up(), vec![(4, 40), (3, 30)]);
+
+			assert_noop!(Council::resign(Origin::signed(2)), "not on council");
+			assert_ok!(Council::resign(Origin::signed(1)));
+			assert_eq!(Council::active_council(), vec![(5, 11), (4, 11)]);
+			assert_eq!(Council::backing_stake_of(4), 40);
+			assert_eq!(Council::backing_stake_of(1), 0);
+			assert_eq!(Council::runners_up(), vec![(3, 30)]);
+			assert!(!Council::is_a_candidate(&4));
+			assert_eq!(Council::candidates(), vec![0, 0, 3, 0]);
+			// the successor's approvals stay with it rather than passing to a new candidate.
+			assert_noop!(Council::submit_candidacy(Origin::signed(6), 3), "slot retracted until the next tally");
+			assert_eq!(Balances::reserved_balance(&4), 3);
+			assert_eq!(System::events().last().unwrap().event, crate::tests::Event::seats(RawEvent::SeatFilled(4)));
+
+			assert_ok!(Council::resign(Origin::signed(5)));
+			assert_eq!(Council::active_council(), vec![(4, 11), (3, 11)]);
+			assert_eq!(Council::candidates(), vec![0, 0, 0, 0]);
+
+			// with no runner-up left, the seat stays empty until the next tally.
+			assert_ok!(Council::resign(Origin::signed(4)));
+			assert_eq!(Council::active_council(), vec![(3, 11)]);
+			assert_eq!(System::events().last().unwrap().event, crate::tests::Event::seats(RawEvent::CouncillorResigned(4)));
+			assert_eq!(Council::next_tally(), Some(6));
+		});
+	}
+
+	#[test]
+	fn low_turnout_should_defer_tally_until_forced() {
+		with_externalities(&mut new_test_ext(false), || {
+			MINIMUM_TURNOUT.with(|t| *t.borrow_mut() = 50);
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true], 0));
+			// 70 of the 210 issued back the voters, short of half.
+			assert_ok!(Council::end_block(System::block_number()));
+			assert!(!Council::presentation_active());
+			assert_eq!(
+				System::events().last().unwrap().event,
+				crate::tests::Event::seats(RawEvent::TallyDeferredLowTurnout(2))
+			);
+			assert_eq!(Council::next_tally(), Some(4));
+
+			assert_ok!(Council::force_tally());
+			assert!(Council::presentation_active());
+			assert_noop!(Council::force_tally(), "cannot force a tally during presentation");
+
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 20, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 50, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::active_council(), vec![(5, 11), (2, 11)]);
+		});
+	}
+
+	#[test]
+	fn low_turnout_should_extend_expiring_terms() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true], 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			System::set_block_number(6);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 20, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 50, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::active_council(), vec![(5, 11), (2, 11)]);
+
+			MINIMUM_TURNOUT.with(|t| *t.borrow_mut() = 50);
+			System::set_block_number(8);
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 0));
+			assert_ok!(Council::set_approvals(Origin::signed(3), vec![true], 1));
+
+			// 100 of the 210 issued back the voters, short of half.
+			System::set_block_number(11);
+			Council::start_tally(true);
+			assert!(!Council::presentation_active());
+			assert_eq!(Council::active_council(), vec![(5, 12), (2, 12)]);
+			assert_eq!(Council::next_tally(), Some(12));
+		});
+	}
+
+	#[test]
+	fn delegated_stake_should_back_delegates_approvals() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(4);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true], 0));
+			assert_ok!(Council::delegate(Origin::signed(6), 1));
+			assert_ok!(Council::delegate(Origin::signed(1), 2));
+			assert_eq!(Council::delegators_of(2), vec![1]);
+
+			assert_noop!(Council::delegate(Origin::signed(2), 6), "voters may not delegate");
+			assert_noop!(Council::set_approvals(Origin::signed(1), vec![true], 0), "delegators may not vote");
+			assert_eq!(Balances::reserved_balance(&1), 2);
+			assert_eq!(Balances::reserved_balance(&6), 2);
+			assert_ok!(Council::end_block(System::block_number()));
+
+			System::set_block_number(6);
+			assert_eq!(Council::snapshoted_stakes(), vec![90, 50]);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 90, 0));
+			assert_ok!(Council::present_winner(Origin::signed(4), 5, 50, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::active_council(), vec![(2, 11), (5, 11)]);
+		});
+	}
+
+	#[test]
+	fn delegation_cycles_should_not_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_ok!(Council::delegate(Origin::signed(1), 2));
+			assert_ok!(Council::delegate(Origin::signed(2), 3));
+			assert_noop!(Council::delegate(Origin::signed(3), 1), "delegation would form a cycle");
+			assert_noop!(Council::delegate(Origin::signed(3), 3), "delegation would form a cycle");
+
+			assert_ok!(Council::undelegate(Origin::signed(1)));
+			assert_eq!(Council::delegate_of(1), None);
+			assert_eq!(Council::delegators_of(2), Vec::<u64>::new());
+			assert_ok!(Council::delegate(Origin::signed(3), 1));
+			assert_noop!(Council::undelegate(Origin::signed(4)), "not delegating");
+			assert_eq!(
+				System::events().last().unwrap().event,
+				crate::tests::Event::seats(RawEvent::Delegated(3, 1))
+			);
+		});
+	}
+
+	#[test]
+	fn candidate_standings_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_eq!(Council::candidate_standings(), vec![]);
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(5), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 2));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 3));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true, false, false, false], 0));
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![false, true, true, false], 0));
+			assert_ok!(Democracy::set_proxy(Origin::signed(6), 7));
+			assert_ok!(Council::proxy_set_approvals(Origin::signed(7), vec![true, false, false, false], 0));
+			assert_ok!(Council::delegate(Origin::signed(1), 5));
+
+			assert_eq!(Council::candidate_standings(), vec![(2, 80), (5, 60), (3, 60), (4, 0)]);
+
+			assert_ok!(Council::retract_candidacy(Origin::signed(5), 1));
+			assert_eq!(Council::candidate_standings(), vec![(2, 80), (3, 60), (4, 0)]);
+		});
+	}
+
+	#[test]
+	fn seating_block_should_match_when_winners_are_seated() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_eq!(Council::seating_block(), Some(6));
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::set_approvals(Origin::signed(2), vec![true], 0));
+
+			System::set_block_number(4);
+			assert_ok!(Council::end_block(System::block_number()));
+			assert!(Council::presentation_active());
+			assert_eq!(Council::seating_block(), Some(6));
+
+			System::set_block_number(5);
+			assert_ok!(Council::present_winner(Origin::signed(4), 2, 20, 0));
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::active_council(), vec![]);
+
+			System::set_block_number(6);
+			assert_ok!(Council::end_block(System::block_number()));
+			assert_eq!(Council::active_council(), vec![(2, 11)]);
+		});
+	}
+
+	#[test]
+	fn approval_concentration_should_work() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_eq!(Council::approval_concentration(), Perbill::zero());
+			assert_ok!(Council::submit_candidacy(Origin::signed(2), 0));
+			assert_ok!(Council::submit_candidacy(Origin::signed(3), 1));
+			assert_ok!(Council::submit_candidacy(Origin::signed(4), 2));
+			assert_eq!(Council::approval_concentration(), Perbill::zero());
+
+			// evenly backed.
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![true, true, true], 0));
+			assert_eq!(Council::approval_concentration(), Perbill::zero());
+
+			// all but one candidate's backing withdrawn.
+			assert_ok!(Council::set_approvals(Origin::signed(5), vec![true], 0));
+			assert_eq!(Council::approval_concentration(), Perbill::from_parts(666_666_666));
+
+			// mostly but not entirely skewed.
+			assert_ok!(Council::set_approvals(Origin::signed(6), vec![false, true], 0));
+			assert_eq!(Council::candidate_standings(), vec![(2, 50), (3, 60), (4, 0)]);
+			assert_eq!(Council::approval_concentration(), Perbill::from_parts(363_636_363));
+		});
+	}
+
+	#[test]
+	fn delegation_should_be_bonded_and_bounded() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_ok!(Council::delegate(Origin::signed(1), 4));
+			assert_ok!(Council::delegate(Origin::signed(2), 4));
+			assert_eq!(Balances::reserved_balance(&1), 2);
+			assert_noop!(Council::delegate(Origin::signed(3), 4), "too many delegators");
+			assert_noop!(Council::delegate(Origin::signed(7), 5), "delegator has not enough funds for the bond");
+
+			// moving a delegation keeps the same bond.
+			assert_ok!(Council::delegate(Origin::signed(1), 4));
+			assert_ok!(Council::delegate(Origin::signed(1), 5));
+			assert_eq!(Balances::reserved_balance(&1), 2);
+			assert_ok!(Council::delegate(Origin::signed(3), 4));
+
+			assert_ok!(Council::undelegate(Origin::signed(1)));
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Council::delegators_of(5), Vec::<u64>::new());
+		});
+	}
+
+	#[test]
+	fn delegation_chains_should_be_bounded_at_both_ends() {
+		with_externalities(&mut new_test_ext(false), || {
+			System::set_block_number(1);
+			assert_eq!(Council::max_delegation_depth(), 2);
+			assert_ok!(Council::delegate(Origin::signed(1), 2));
+			assert_ok!(Council::delegate(Origin::signed(2), 3));
+			assert_noop!(Council::delegate(Origin::signed(4), 1), "delegation chain too deep");
+			assert_noop!(Council::delegate(Origin::signed(3), 4), "delegation chain too deep");
+
+			// chains made under a higher limit are counted in full.
+			assert_ok!(Council::set_max_delegation_depth(1));
+			assert_eq!(Council::delegated_stake_of(&3), 30);
+		});
+	}
 }
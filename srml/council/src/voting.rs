@@ -18,52 +18,279 @@
 
 use rstd::prelude::*;
 use rstd::borrow::Borrow;
-use primitives::traits::{Hash, Zero};
+use primitives::Perbill;
+use primitives::traits::{Hash, Zero, Saturating, UniqueSaturatedInto};
 use runtime_io::print;
 use srml_support::dispatch::Result;
-use srml_support::{StorageValue, StorageMap, IsSubType, decl_module, decl_storage, decl_event, ensure};
+use srml_support::{StorageValue, StorageMap, EnumerableStorageMap, IsSubType, decl_module, decl_storage, decl_event, ensure};
+use srml_support::traits::{Currency, ReservableCurrency, Get};
+use parity_codec::{Encode, Decode};
 use {system, democracy};
 use super::{Trait as CouncilTrait, Module as Council};
 use system::ensure_signed;
 
+type BalanceOf<T> = <<T as democracy::Trait>::Currency as Currency<<T as system::Trait>::AccountId>>::Balance;
+
+/// The number of times a proposal may be postponed for abstentions before it is tallied regardless.
+pub const MAX_POSTPONEMENTS: u32 = 3;
+
 pub trait Trait: CouncilTrait {
 	type Event: From<Event<Self>> + Into<<Self as system::Trait>::Event>;
+
+	/// How much each councillor's vote counts for when deciding whether a proposal is elevated.
+	type VoteWeighting: VoteWeight<Self>;
+
+	/// The fraction of the council which must co-sign the override of a veto for it to be lifted.
+	type VetoOverrideThreshold: Get<Perbill>;
+
+	/// The shortest enactment delay a proposal may request for itself.
+	type MinEnactDelay: Get<Self::BlockNumber>;
+}
+
+/// The weight given to councillors' votes on council proposals.
+pub trait VoteWeight<T: Trait> {
+	/// The weight of the vote of councillor `who`, or `None` if all votes weigh the same.
+	fn vote_weight(who: &T::AccountId) -> Option<u128>;
+}
+
+/// One vote per councillor.
+impl<T: Trait> VoteWeight<T> for () {
+	fn vote_weight(_who: &T::AccountId) -> Option<u128> { None }
+}
+
+/// Votes weighted by the approval stake with which each councillor was elected.
+pub struct StakeWeighted;
+impl<T: Trait> VoteWeight<T> for StakeWeighted {
+	fn vote_weight(who: &T::AccountId) -> Option<u128> {
+		Some(<Council<T>>::backing_stake_of(who).unique_saturated_into())
+	}
+}
+
+/// How the quorum of council proposals is measured.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum QuorumBy {
+	/// `Quorum` is the number of councillors who must vote.
+	MemberCount,
+	/// `StakeQuorum` is the part of the council's backing stake held by councillors who must vote.
+	BackingStake,
+}
+
+impl Default for QuorumBy {
+	fn default() -> Self {
+		QuorumBy::MemberCount
+	}
+}
+
+/// A condition on the block height at which a passed proposal is elevated to a referendum.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Debug))]
+pub enum ElevationCondition<BlockNumber> {
+	/// Holds until the given block.
+	Before(BlockNumber),
+	/// Holds from the given block on.
+	NotBefore(BlockNumber),
+}
+
+impl<BlockNumber: PartialOrd> ElevationCondition<BlockNumber> {
+	/// Whether the condition holds at block `now`.
+	pub fn holds(&self, now: &BlockNumber) -> bool {
+		match *self {
+			ElevationCondition::Before(ref n) => now < n,
+			ElevationCondition::NotBefore(ref n) => now >= n,
+		}
+	}
 }
 
 decl_module! {
 	pub struct Module<T: Trait> for enum Call where origin: T::Origin {
 		fn deposit_event<T>() = default;
 
-		fn propose(origin, proposal: Box<T::Proposal>) {
+		fn propose(origin, proposal: Box<T::Proposal>) -> Result {
+			let who = ensure_signed(origin)?;
+			Self::do_propose(who, proposal, None, None, None)
+		}
+
+		/// Propose a motion with any of these options:
+		/// - `depends_on`: it is only elevated to a referendum if the proposal with this hash has
+		///   already passed the council vote.
+		/// - `condition`: once passed, it is only elevated to a referendum if the condition holds
+		///   at the block it is tallied.
+		/// - `enact_delay`: should its referendum pass, it is enacted this many blocks later
+		///   instead of after the usual `EnactDelayPeriod`.
+		fn propose_with_options(
+			origin,
+			proposal: Box<T::Proposal>,
+			depends_on: Option<T::Hash>,
+			condition: Option<ElevationCondition<T::BlockNumber>>,
+			enact_delay: Option<T::BlockNumber>
+		) -> Result {
+			let who = ensure_signed(origin)?;
+			Self::do_propose(who, proposal, depends_on, condition, enact_delay)
+		}
+
+		fn vote(origin, proposal: T::Hash, approve: bool) {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_councillor(&who), "only councillors may vote on council proposals");
+			ensure!(<ProposalOf<T>>::exists(proposal), "proposal must exist");
+
+			let previous = Self::vote_of((proposal, who.clone()));
+			if previous.is_none() {
+				<ProposalVoters<T>>::mutate(proposal, |voters| voters.push(who.clone()));
+			}
+			<CouncilVoteOf<T>>::insert((proposal, who), approve);
+			<CachedTally<T>>::mutate(proposal, |tally| {
+				match previous {
+					Some(true) => tally.0 = tally.0.saturating_sub(1),
+					Some(false) => tally.1 = tally.1.saturating_sub(1),
+					None => {}
+				}
+				if approve { tally.0 += 1 } else { tally.1 += 1 }
+			});
+		}
+
+		/// Replace the sender's proposal `old_hash` with `new_proposal`, keeping its place in the
+		/// queue and its dependency. The deposit is topped up or partly returned to match the size
+		/// of `new_proposal`. Only possible while no one else has voted on it.
+		fn amend_proposal(origin, old_hash: T::Hash, new_proposal: Box<T::Proposal>) {
+			let who = ensure_signed(origin)?;
+
+			let (proposer, deposit) = Self::deposit_of(&old_hash).ok_or("proposal must exist")?;
+			ensure!(proposer == who, "only the proposer may amend a proposal");
+			ensure!(Self::proposal_voters(&old_hash) == vec![who.clone()], "proposal already voted on");
+
+			let new_hash = T::Hashing::hash_of(&new_proposal);
+			ensure!(!<ProposalOf<T>>::exists(new_hash), "duplicate proposals not allowed");
+			ensure!(!Self::is_vetoed(&new_hash), "proposal is vetoed");
+			ensure!(Self::depends_on(&old_hash) != Some(new_hash), "proposal may not depend on itself");
+
+			let new_deposit = Self::proposal_deposit(new_proposal.encode().len());
+			if new_deposit > deposit {
+				// NOTE: This must be last as it has side-effects.
+				T::Currency::reserve(&who, new_deposit - deposit)
+					.map_err(|_| "proposer's balance too low")?;
+			} else {
+				T::Currency::unreserve(&who, deposit - new_deposit);
+			}
+
+			Self::set_proposals(
+				&Self::proposals().into_iter()
+					.map(|(expiry, h)| (expiry, if h == old_hash { new_hash } else { h }))
+					.collect::<Vec<_>>()
+			);
+			<ProposalOf<T>>::remove(old_hash);
+			<ProposalOf<T>>::insert(new_hash, *new_proposal);
+			<ProposalVoters<T>>::insert(new_hash, <ProposalVoters<T>>::take(old_hash));
+			if let Some(approve) = <CouncilVoteOf<T>>::take((old_hash, who.clone())) {
+				<CouncilVoteOf<T>>::insert((new_hash, who.clone()), approve);
+			}
+			<CachedTally<T>>::insert(new_hash, <CachedTally<T>>::take(old_hash));
+			<DepositOf<T>>::remove(old_hash);
+			<DepositOf<T>>::insert(new_hash, (who, new_deposit));
+			if <QuorumCarried<T>>::take(old_hash) {
+				<QuorumCarried<T>>::insert(new_hash, true);
+			}
+			<PostponementsOf<T>>::insert(new_hash, <PostponementsOf<T>>::take(old_hash));
+			if let Some(dependency) = <DependsOn<T>>::take(old_hash) {
+				<DependsOn<T>>::insert(new_hash, dependency);
+			}
+			if let Some(condition) = <ElevationConditionOf<T>>::take(old_hash) {
+				<ElevationConditionOf<T>>::insert(new_hash, condition);
+			}
+			if let Some(delay) = <EnactDelayOf<T>>::take(old_hash) {
+				<EnactDelayOf<T>>::insert(new_hash, delay);
+			}
+
+			Self::deposit_event(RawEvent::Amended(old_hash, new_hash));
+		}
+
+		/// Propose a choice among several `options`, to be decided by instant-runoff over the
+		/// councillors' rankings of them at the end of the voting period.
+		///
+		/// The outcome is advisory only: the options are bare hashes, so the chosen one is just
+		/// announced in a `MultiOptionChosen` event and nothing is elevated to a referendum or
+		/// dispatched. For the same reason no deposit is taken and vetoes are not consulted; a
+		/// chosen option still has to be proposed through `propose` to take effect.
+		fn propose_multi(origin, options: Vec<T::Hash>) {
 			let who = ensure_signed(origin)?;
 
 			let expiry = <system::Module<T>>::block_number() + Self::voting_period();
 			ensure!(Self::will_still_be_councillor_at(&who, expiry), "proposer would not be on council");
+			ensure!(options.len() >= 2, "a multi-option proposal needs at least two options");
+			ensure!(options.len() <= u8::max_value() as usize + 1, "too many options");
+			ensure!(
+				options.iter().enumerate().all(|(i, o)| !options[..i].contains(o)),
+				"duplicate options not allowed"
+			);
 
-			let proposal_hash = T::Hashing::hash_of(&proposal);
+			let proposal_hash = T::Hashing::hash_of(&options);
+			ensure!(!<MultiProposalOptions<T>>::exists(proposal_hash), "duplicate proposals not allowed");
+
+			<MultiProposals<T>>::mutate(|proposals| {
+				proposals.push((expiry, proposal_hash));
+				proposals.sort_by_key(|&(expiry, _)| expiry);
+			});
+			<MultiProposalOptions<T>>::insert(proposal_hash, options);
+		}
 
-			ensure!(!<ProposalOf<T>>::exists(proposal_hash), "duplicate proposals not allowed");
-			ensure!(!Self::is_vetoed(&proposal_hash), "proposal is vetoed");
+		/// Rank the options of a multi-option proposal, given by their index, most preferred
+		/// first. Options left out are not preferred to any.
+		fn rank_vote(origin, proposal: T::Hash, ranking: Vec<u8>) {
+			let who = ensure_signed(origin)?;
 
-			let mut proposals = Self::proposals();
-			proposals.push((expiry, proposal_hash));
-			proposals.sort_by_key(|&(expiry, _)| expiry);
-			Self::set_proposals(&proposals);
+			ensure!(Self::is_councillor(&who), "only councillors may vote on council proposals");
+			let options = Self::multi_proposal_options(&proposal);
+			ensure!(!options.is_empty(), "proposal must exist");
+			ensure!(!ranking.is_empty(), "ranking must name at least one option");
+			ensure!(ranking.iter().all(|&o| (o as usize) < options.len()), "ranking names an unknown option");
+			ensure!(
+				ranking.iter().enumerate().all(|(i, o)| !ranking[..i].contains(o)),
+				"ranking names an option twice"
+			);
 
-			<ProposalOf<T>>::insert(proposal_hash, *proposal);
-			<ProposalVoters<T>>::insert(proposal_hash, vec![who.clone()]);
-			<CouncilVoteOf<T>>::insert((proposal_hash, who.clone()), true);
+			if !<RankingOf<T>>::exists((proposal, who.clone())) {
+				<MultiProposalVoters<T>>::mutate(proposal, |voters| voters.push(who.clone()));
+			}
+			<RankingOf<T>>::insert((proposal, who), ranking);
 		}
 
-		fn vote(origin, proposal: T::Hash, approve: bool) {
+		/// Have the votes of `to` count for the sender on any proposal the sender doesn't vote on
+		/// directly. `to` may itself have delegated, and others may have delegated to the sender,
+		/// up to the council's `MaxDelegationDepth` hops in all.
+		fn delegate(origin, to: T::AccountId) {
 			let who = ensure_signed(origin)?;
 
-			ensure!(Self::is_councillor(&who), "only councillors may vote on council proposals");
+			ensure!(Self::is_councillor(&who), "only councillors may delegate");
+			ensure!(Self::is_councillor(&to), "may only delegate to a councillor");
+
+			let max_depth = <Council<T>>::max_delegation_depth();
+			// the chains already ending at the sender are extended by this delegation.
+			let mut depth = Self::delegation_depth_to(&who) + 1;
+			let mut next = to.clone();
+			loop {
+				ensure!(next != who, "delegation would form a cycle");
+				ensure!(depth <= max_depth, "delegation chain too deep");
+				match Self::delegate_of(&next) {
+					Some(d) => {
+						next = d;
+						depth += 1;
+					}
+					None => break,
+				}
+			}
 
-			if Self::vote_of((proposal, who.clone())).is_none() {
-				<ProposalVoters<T>>::mutate(proposal, |voters| voters.push(who.clone()));
+			if !<DelegateOf<T>>::exists(&who) {
+				<DelegationCount<T>>::mutate(|c| *c += 1);
 			}
-			<CouncilVoteOf<T>>::insert((proposal, who), approve);
+			<DelegateOf<T>>::insert(&who, to);
+		}
+
+		fn undelegate(origin) {
+			let who = ensure_signed(origin)?;
+			ensure!(<DelegateOf<T>>::exists(&who), "not delegating");
+			<DelegateOf<T>>::remove(&who);
+			<DelegationCount<T>>::mutate(|c| *c -= 1);
 		}
 
 		fn veto(origin, proposal_hash: T::Hash) {
@@ -77,20 +304,74 @@ decl_module! {
 				.unwrap_or_else(Vec::new);
 			let insert_position = existing_vetoers.binary_search(&who)
 				.err().ok_or("a councillor may not veto a proposal twice")?;
+			let now = <system::Module<T>>::block_number();
 			existing_vetoers.insert(insert_position, who);
-			Self::set_veto_of(
-				&proposal_hash,
-				<system::Module<T>>::block_number() + Self::cooloff_period(),
-				existing_vetoers
-			);
 
-			Self::set_proposals(
-				&Self::proposals().into_iter().filter(|&(_, h)| h != proposal_hash
-			).collect::<Vec<_>>());
-			<ProposalVoters<T>>::remove(proposal_hash);
-			<ProposalOf<T>>::remove(proposal_hash);
-			for (c, _) in <Council<T>>::active_council() {
-				<CouncilVoteOf<T>>::remove((proposal_hash, c));
+			if Self::is_cooloff_exempt(&proposal_hash) {
+				// the veto is noted, but the proposal stays on the table.
+				Self::set_veto_of(&proposal_hash, now, existing_vetoers);
+				return Ok(());
+			}
+
+			let cooloff = Self::cooloff_after_vetoes(existing_vetoers.len() as u32 - 1);
+			Self::set_veto_of(&proposal_hash, now + cooloff, existing_vetoers);
+			<VetoOverrides<T>>::remove(&proposal_hash);
+
+			Self::remove_proposal(&proposal_hash);
+		}
+
+		/// Set whether a veto on `proposal_hash` is merely noted rather than taking it off the
+		/// table for a cooloff period, e.g. for emergency fixes.
+		fn set_cooloff_exempt(proposal_hash: T::Hash, exempt: bool) {
+			if exempt {
+				<CooloffExempt<T>>::insert(proposal_hash, true);
+			} else {
+				<CooloffExempt<T>>::remove(proposal_hash);
+			}
+		}
+
+		/// Co-sign the override of the active veto on `proposal_hash`. Once at least
+		/// `VetoOverrideThreshold` of the council has co-signed, the veto is lifted. The veto took
+		/// the proposal off the table, so it then has to be proposed anew.
+		fn override_veto(origin, proposal_hash: T::Hash) {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_councillor(&who), "only councillors may override vetoes");
+			ensure!(Self::is_vetoed(&proposal_hash), "proposal is not vetoed");
+
+			let mut signers = Self::veto_overrides(&proposal_hash);
+			ensure!(!signers.contains(&who), "a councillor may not co-sign an override twice");
+			signers.push(who);
+
+			// compared in parts per billion so that a fraction of a councillor rounds up.
+			let council_size = <Council<T>>::active_council().len() as u64;
+			let threshold = T::VetoOverrideThreshold::get() * 1_000_000_000u64;
+			if signers.len() as u64 * 1_000_000_000 >= threshold * council_size {
+				<VetoOverrides<T>>::remove(&proposal_hash);
+				Self::kill_veto_of(&proposal_hash);
+				Self::deposit_event(RawEvent::VetoOverridden(proposal_hash));
+			} else {
+				<VetoOverrides<T>>::insert(&proposal_hash, signers);
+			}
+		}
+
+		/// Cancel each of the referenda `indices` for which an open council proposal to cancel it
+		/// has already been approved unanimously, without waiting for its tally. The others are
+		/// left as they are.
+		fn cancel_referenda(origin, indices: Vec<democracy::ReferendumIndex>) {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::is_councillor(&who), "only councillors may cancel referenda");
+
+			for ref_index in indices {
+				let cancelled = match Self::unanimous_cancellation_of(ref_index) {
+					Some(proposal_hash) => {
+						Self::remove_proposal(&proposal_hash);
+						<democracy::Module<T>>::internal_cancel_referendum(ref_index);
+						true
+					}
+					None => false,
+				};
+				Self::deposit_event(RawEvent::ReferendumCancellation(ref_index, cancelled));
 			}
 		}
 
@@ -98,10 +379,35 @@ decl_module! {
 			<CooloffPeriod<T>>::put(blocks);
 		}
 
+		fn set_max_cooloff_period(#[compact] blocks: T::BlockNumber) {
+			<MaxCooloffPeriod<T>>::put(blocks);
+		}
+
 		fn set_voting_period(#[compact] blocks: T::BlockNumber) {
 			<VotingPeriod<T>>::put(blocks);
 		}
 
+		fn set_quorum(#[compact] count: u32) {
+			<Quorum<T>>::put(count);
+		}
+
+		fn set_quorum_measure(measure: QuorumBy) {
+			<QuorumMeasure<T>>::put(measure);
+		}
+
+		fn set_stake_quorum(quorum: Perbill) {
+			<StakeQuorum<T>>::put(quorum);
+		}
+
+		fn set_proposal_deposit(#[compact] base: BalanceOf<T>, #[compact] per_byte: BalanceOf<T>) {
+			<ProposalDepositBase<T>>::put(base);
+			<DepositPerByte<T>>::put(per_byte);
+		}
+
+		fn set_postponement_threshold(#[compact] count: u32) {
+			<PostponementThreshold<T>>::put(count);
+		}
+
 		fn on_finalize(n: T::BlockNumber) {
 			if let Err(e) = Self::end_block(n) {
 				print("Guru meditation");
@@ -114,25 +420,98 @@ decl_module! {
 decl_storage! {
 	trait Store for Module<T: Trait> as CouncilVoting {
 		pub CooloffPeriod get(cooloff_period) config(): T::BlockNumber = 1000.into();
+		/// The longest cooloff a proposal can be given, however many times it has been vetoed.
+		pub MaxCooloffPeriod get(max_cooloff_period) config(): T::BlockNumber = 10000.into();
 		pub VotingPeriod get(voting_period) config(): T::BlockNumber = 3.into();
 		/// Number of blocks by which to delay enactment of successful, non-unanimous-council-instigated referendum proposals.
 		pub EnactDelayPeriod get(enact_delay_period) config(): T::BlockNumber = 0.into();
+		/// The enactment delay a proposal requested in place of `EnactDelayPeriod`, if any.
+		pub EnactDelayOf get(enact_delay_of): map T::Hash => Option<T::BlockNumber>;
+		/// Minimum number of councillors that must vote on a proposal for its tally to count, when
+		/// quorum is measured by member count. A proposal failing to reach quorum is carried over
+		/// for one more voting period before being rejected.
+		pub Quorum get(quorum) config(): u32;
+		/// Minimum part of the council's backing stake that must be held by councillors voting on a
+		/// proposal for its tally to count, when quorum is measured by backing stake.
+		pub StakeQuorum get(stake_quorum): Perbill;
+		/// Whether quorum is measured against `Quorum` or `StakeQuorum`.
+		pub QuorumMeasure get(quorum_measure): QuorumBy;
+		/// Number of councillors who must abstain on a proposal for it to be postponed by another
+		/// voting period rather than tallied. Zero disables postponement.
+		pub PostponementThreshold get(postponement_threshold) config(): u32;
+		/// The part of a proposal's deposit which doesn't depend on its size.
+		pub ProposalDepositBase get(proposal_deposit_base) config(): BalanceOf<T>;
+		/// The part of a proposal's deposit charged per byte of the encoded proposal.
+		pub DepositPerByte get(deposit_per_byte) config(): BalanceOf<T>;
+		/// The account which proposed a proposal and the deposit they have reserved for it.
+		pub DepositOf get(deposit_of): map T::Hash => Option<(T::AccountId, BalanceOf<T>)>;
 		pub Proposals get(proposals) build(|_| vec![]): Vec<(T::BlockNumber, T::Hash)>; // ordered by expiry.
 		pub ProposalOf get(proposal_of): map T::Hash => Option<T::Proposal>;
 		pub ProposalVoters get(proposal_voters): map T::Hash => Vec<T::AccountId>;
 		pub CouncilVoteOf get(vote_of): map (T::Hash, T::AccountId) => Option<bool>;
 		pub VetoedProposal get(veto_of): map T::Hash => Option<(T::BlockNumber, Vec<T::AccountId>)>;
+		/// Proposals which a veto does not take off the table.
+		pub CooloffExempt get(is_cooloff_exempt): map T::Hash => bool;
+		/// The councillors who have co-signed the override of the active veto on a proposal.
+		pub VetoOverrides get(veto_overrides): map T::Hash => Vec<T::AccountId>;
+		/// Whether a proposal has already been carried over once for failing to reach quorum.
+		pub QuorumCarried get(quorum_carried): map T::Hash => bool;
+		/// The number of times a proposal has been postponed for abstentions, at most `MAX_POSTPONEMENTS`.
+		pub PostponementsOf get(postponements_of): map T::Hash => u32;
+		/// The proposal which must have passed before a given proposal may be elevated.
+		pub DependsOn get(depends_on): map T::Hash => Option<T::Hash>;
+		/// The condition on which a proposal is elevated to a referendum once passed, if any.
+		pub ElevationConditionOf get(elevation_condition_of): map T::Hash => Option<ElevationCondition<T::BlockNumber>>;
+		/// Proposals which have passed the council vote and been elevated to a referendum.
+		pub PassedProposals get(has_passed): map T::Hash => bool;
+		/// The councillor to whom a councillor has delegated their vote. Dropped once either of
+		/// them leaves the council.
+		pub DelegateOf get(delegate_of): linked_map T::AccountId => Option<T::AccountId>;
+		/// The number of councillors currently delegating their vote.
+		pub DelegationCount get(delegation_count): u32;
+		/// Running count of the (yes, no) votes cast on a proposal, kept up to date as votes arrive.
+		pub CachedTally get(cached_tally): map T::Hash => (u32, u32);
+		/// The open multi-option proposals, ordered by expiry.
+		pub MultiProposals get(multi_proposals): Vec<(T::BlockNumber, T::Hash)>;
+		/// The options of an open multi-option proposal.
+		pub MultiProposalOptions get(multi_proposal_options): map T::Hash => Vec<T::Hash>;
+		/// The councillors who have ranked the options of a multi-option proposal.
+		pub MultiProposalVoters get(multi_proposal_voters): map T::Hash => Vec<T::AccountId>;
+		/// A councillor's ranking of the options of a multi-option proposal by index, most
+		/// preferred first.
+		pub RankingOf get(ranking_of): map (T::Hash, T::AccountId) => Option<Vec<u8>>;
 	}
 }
 
 decl_event!(
-	pub enum Event<T> where <T as system::Trait>::Hash {
+	pub enum Event<T> where <T as system::Trait>::Hash, <T as system::Trait>::BlockNumber {
 		/// A voting tally has happened for a referendum cancellation vote.
 		/// Last three are yes, no, abstain counts.
 		TallyCancelation(Hash, u32, u32, u32),
 		/// A voting tally has happened for a referendum vote.
 		/// Last three are yes, no, abstain counts.
 		TallyReferendum(Hash, u32, u32, u32),
+		/// A proposal did not reach quorum and has been carried over for another voting period.
+		QuorumNotMetCarried(Hash),
+		/// Enough councillors abstained on a proposal for it to be postponed by another voting period.
+		Postponed(Hash),
+		/// A proposal passed but was not elevated since the proposal it depends on (second hash)
+		/// has not passed.
+		DependencyUnmet(Hash, Hash),
+		/// A proposal (first hash) was amended, becoming a new proposal (second hash).
+		Amended(Hash, Hash),
+		/// A multi-option proposal was decided in favour of an option (second hash). This is
+		/// advisory only; the option is not elevated.
+		MultiOptionChosen(Hash, Hash),
+		/// A multi-option proposal was left undecided since no councillor ranked its options.
+		MultiOptionUndecided(Hash),
+		/// A proposal passed but was not elevated since its elevation condition failed at the
+		/// given block.
+		ElevationConditionFailed(Hash, BlockNumber),
+		/// A referendum was (`true`) or was not (`false`) cancelled as part of a batch.
+		ReferendumCancellation(u32, bool),
+		/// The veto on a proposal was lifted by the council. It may now be proposed again.
+		VetoOverridden(Hash),
 	}
 );
 
@@ -155,11 +534,278 @@ impl<T: Trait> Module<T> {
 			.any(|&(ref a, _)| a == who)
 	}
 
+	/// The yes, no and abstain counts of a proposal. Read from the running vote count unless
+	/// some councillor is delegating or someone who voted has since left the council, in which
+	/// case the votes have to be resolved one by one.
 	pub fn tally(proposal_hash: &T::Hash) -> (u32, u32, u32) {
-		Self::generic_tally(proposal_hash, |w: &T::AccountId, p: &T::Hash| Self::vote_of((*p, w.clone())))
+		let council = <Council<T>>::active_council();
+		let voter_left = Self::proposal_voters(proposal_hash).iter()
+			.any(|v| !council.iter().any(|&(ref a, _)| a == v));
+		if Self::delegation_count() > 0 || voter_left {
+			return Self::recomputed_tally(proposal_hash);
+		}
+		let (approve, reject) = Self::cached_tally(proposal_hash);
+		(approve, reject, (council.len() as u32).saturating_sub(approve + reject))
+	}
+
+	/// The yes, no and abstain counts of a proposal, computed afresh from every councillor's vote.
+	pub fn recomputed_tally(proposal_hash: &T::Hash) -> (u32, u32, u32) {
+		Self::generic_tally(proposal_hash, |w: &T::AccountId, p: &T::Hash| Self::resolved_vote_of(p, w))
+	}
+
+	/// The yes, no and abstain weights of a proposal under `VoteWeighting`, or `None` if every
+	/// councillor's vote weighs the same.
+	pub fn weighted_tally(proposal_hash: &T::Hash) -> Option<(u128, u128, u128)> {
+		let mut tally = (0u128, 0u128, 0u128);
+		for (c, _) in <Council<T>>::active_council() {
+			let weight = T::VoteWeighting::vote_weight(&c)?;
+			let bucket = match Self::resolved_vote_of(proposal_hash, &c) {
+				Some(true) => &mut tally.0,
+				Some(false) => &mut tally.1,
+				None => &mut tally.2,
+			};
+			*bucket = bucket.saturating_add(weight);
+		}
+		Some(tally)
+	}
+
+	/// Whether the votes cast on a proposal, `approve` and `reject` of them, reach the quorum as
+	/// measured by `QuorumMeasure`.
+	fn quorum_met(proposal_hash: &T::Hash, approve: u32, reject: u32) -> bool {
+		match Self::quorum_measure() {
+			QuorumBy::MemberCount => approve + reject >= Self::quorum(),
+			QuorumBy::BackingStake => {
+				let (voted, total) = <Council<T>>::active_council().iter()
+					.fold((BalanceOf::<T>::zero(), BalanceOf::<T>::zero()), |(voted, total), &(ref c, _)| {
+						let stake = <Council<T>>::backing_stake_of(c);
+						let voted = match Self::resolved_vote_of(proposal_hash, c) {
+							Some(_) => voted.saturating_add(stake),
+							None => voted,
+						};
+						(voted, total.saturating_add(stake))
+					});
+				voted >= Self::stake_quorum() * total
+			}
+		}
+	}
+
+	/// The option chosen by instant-runoff among `option_count` options, given `ballots` each
+	/// ranking some of them by index. Each round, the option ranked highest by the fewest
+	/// ballots is eliminated, the latest such option on a tie, until one is ranked highest by a
+	/// majority of the ballots still ranking any option. `None` if no ballot ranks any option.
+	pub fn instant_runoff(option_count: usize, ballots: &[Vec<u8>]) -> Option<usize> {
+		let mut remaining = vec![true; option_count];
+		loop {
+			let mut counts = vec![0u32; option_count];
+			let mut active = 0u32;
+			for ballot in ballots {
+				if let Some(&choice) = ballot.iter().find(|&&o| remaining.get(o as usize) == Some(&true)) {
+					counts[choice as usize] += 1;
+					active += 1;
+				}
+			}
+			if active == 0 {
+				return None;
+			}
+
+			let mut leader: Option<usize> = None;
+			let mut last: Option<usize> = None;
+			for i in (0..option_count).filter(|&i| remaining[i]) {
+				if leader.map_or(true, |l| counts[i] > counts[l]) {
+					leader = Some(i);
+				}
+				if last.map_or(true, |l| counts[i] <= counts[l]) {
+					last = Some(i);
+				}
+			}
+			let (leader, last) = (leader?, last?);
+			if counts[leader] * 2 > active || leader == last {
+				return Some(leader);
+			}
+			remaining[last] = false;
+		}
+	}
+
+	/// Decide the multi-option proposals expiring at block `now`, announcing the outcome of each.
+	fn end_multi_proposals(now: T::BlockNumber) {
+		let mut proposals = Self::multi_proposals();
+		let expiring = proposals.iter().take_while(|&&(expiry, _)| expiry <= now).count();
+		if expiring == 0 {
+			return;
+		}
+		for (_, proposal_hash) in proposals.drain(..expiring) {
+			let options = <MultiProposalOptions<T>>::take(&proposal_hash);
+			let ballots = <MultiProposalVoters<T>>::take(&proposal_hash).into_iter()
+				.filter_map(|voter| {
+					let ranking = <RankingOf<T>>::take((proposal_hash, voter.clone()));
+					if Self::is_councillor(&voter) { ranking } else { None }
+				})
+				.collect::<Vec<_>>();
+			match Self::instant_runoff(options.len(), &ballots) {
+				Some(winner) => Self::deposit_event(RawEvent::MultiOptionChosen(proposal_hash, options[winner])),
+				None => Self::deposit_event(RawEvent::MultiOptionUndecided(proposal_hash)),
+			}
+		}
+		<MultiProposals<T>>::put(proposals);
+	}
+
+	/// The vote counted for `who` on a proposal: their own if they voted, otherwise that of the
+	/// end of their delegation chain, followed for no more than the council's `MaxDelegationDepth`
+	/// hops.
+	pub fn resolved_vote_of(proposal_hash: &T::Hash, who: &T::AccountId) -> Option<bool> {
+		let mut voter = who.clone();
+		for _ in 0..=<Council<T>>::max_delegation_depth() {
+			if let Some(approve) = Self::vote_of((*proposal_hash, voter.clone())) {
+				return Some(approve);
+			}
+			match Self::delegate_of(&voter) {
+				Some(d) => voter = d,
+				None => return None,
+			}
+		}
+		None
+	}
+
+	/// The number of hops in the longest chain of delegations ending at `who`.
+	fn delegation_depth_to(who: &T::AccountId) -> u32 {
+		let max_depth = <Council<T>>::max_delegation_depth();
+		<DelegateOf<T>>::enumerate()
+			.filter_map(|(_, mut next)| {
+				let mut hops = 1;
+				loop {
+					if next == *who {
+						return Some(hops);
+					}
+					if hops >= max_depth {
+						return None;
+					}
+					next = Self::delegate_of(&next)?;
+					hops += 1;
+				}
+			})
+			.max()
+			.unwrap_or(0)
+	}
+
+	/// Drop the delegations of and to councillors who have left the council, which would
+	/// otherwise keep the running vote count out of use for good.
+	fn prune_delegations() {
+		if Self::delegation_count() == 0 {
+			return;
+		}
+		let stale = <DelegateOf<T>>::enumerate()
+			.filter(|(who, to)| !Self::is_councillor(who) || !Self::is_councillor(to))
+			.map(|(who, _)| who)
+			.collect::<Vec<_>>();
+		for who in stale {
+			<DelegateOf<T>>::remove(&who);
+			<DelegationCount<T>>::mutate(|c| *c -= 1);
+		}
 	}
 
 	// Private
+	fn do_propose(
+		who: T::AccountId,
+		proposal: Box<T::Proposal>,
+		depends_on: Option<T::Hash>,
+		condition: Option<ElevationCondition<T::BlockNumber>>,
+		enact_delay: Option<T::BlockNumber>,
+	) -> Result {
+		let expiry = <system::Module<T>>::block_number() + Self::voting_period();
+		ensure!(Self::will_still_be_councillor_at(&who, expiry), "proposer would not be on council");
+
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+
+		ensure!(!<ProposalOf<T>>::exists(proposal_hash), "duplicate proposals not allowed");
+		ensure!(!Self::is_vetoed(&proposal_hash), "proposal is vetoed");
+		ensure!(depends_on != Some(proposal_hash), "proposal may not depend on itself");
+		ensure!(enact_delay.map_or(true, |d| d >= T::MinEnactDelay::get()), "enactment delay too short");
+
+		let deposit = Self::proposal_deposit(proposal.encode().len());
+		T::Currency::reserve(&who, deposit)
+			.map_err(|_| "proposer's balance too low")?;
+		<DepositOf<T>>::insert(proposal_hash, (who.clone(), deposit));
+
+		let mut proposals = Self::proposals();
+		proposals.push((expiry, proposal_hash));
+		proposals.sort_by_key(|&(expiry, _)| expiry);
+		Self::set_proposals(&proposals);
+
+		<ProposalOf<T>>::insert(proposal_hash, *proposal);
+		<ProposalVoters<T>>::insert(proposal_hash, vec![who.clone()]);
+		<CouncilVoteOf<T>>::insert((proposal_hash, who.clone()), true);
+		<CachedTally<T>>::insert(proposal_hash, (1, 0));
+		if let Some(dependency) = depends_on {
+			<DependsOn<T>>::insert(proposal_hash, dependency);
+		}
+		if let Some(condition) = condition {
+			<ElevationConditionOf<T>>::insert(proposal_hash, condition);
+		}
+		if let Some(delay) = enact_delay {
+			<EnactDelayOf<T>>::insert(proposal_hash, delay);
+		}
+		Ok(())
+	}
+
+	/// The deposit to be reserved for a proposal whose encoding is `len` bytes long.
+	pub fn proposal_deposit(len: usize) -> BalanceOf<T> {
+		let len = BalanceOf::<T>::from(len as u32);
+		Self::proposal_deposit_base().saturating_add(Self::deposit_per_byte().saturating_mul(len))
+	}
+
+	fn refund_deposit(proposal_hash: &T::Hash) {
+		if let Some((who, deposit)) = <DepositOf<T>>::take(proposal_hash) {
+			T::Currency::unreserve(&who, deposit);
+		}
+	}
+
+	/// The cooloff given to a proposal which has already been vetoed `prior_vetoes` times. It
+	/// grows by one base `CooloffPeriod` per prior veto, up to `MaxCooloffPeriod`.
+	pub fn cooloff_after_vetoes(prior_vetoes: u32) -> T::BlockNumber {
+		Self::cooloff_period()
+			.saturating_mul(T::BlockNumber::from(prior_vetoes.saturating_add(1)))
+			.min(Self::max_cooloff_period())
+	}
+
+	/// The open proposal to cancel the referendum `ref_index`, provided the referendum is ongoing
+	/// and every councillor has voted for the proposal.
+	fn unanimous_cancellation_of(ref_index: democracy::ReferendumIndex) -> Option<T::Hash> {
+		if <democracy::Module<T>>::referendum_info(ref_index).is_none() {
+			return None;
+		}
+		let proposal_hash = Self::proposals().into_iter()
+			.map(|(_, h)| h)
+			.find(|h| Self::proposal_of(h).map_or(false, |p|
+				match IsSubType::<democracy::Module<T>>::is_aux_sub_type(&p) {
+					Some(&democracy::Call::cancel_referendum(r)) => r.0 == ref_index,
+					_ => false,
+				}
+			))?;
+		match Self::tally(&proposal_hash) {
+			(approve, 0, 0) if approve > 0 && Self::quorum_met(&proposal_hash, approve, 0) => Some(proposal_hash),
+			_ => None,
+		}
+	}
+
+	/// Remove the open proposal `proposal_hash` with all its votes, refunding its deposit.
+	fn remove_proposal(proposal_hash: &T::Hash) {
+		Self::set_proposals(
+			&Self::proposals().into_iter().filter(|&(_, h)| h != *proposal_hash
+		).collect::<Vec<_>>());
+		<ProposalVoters<T>>::remove(proposal_hash);
+		<ProposalOf<T>>::remove(proposal_hash);
+		<CachedTally<T>>::remove(proposal_hash);
+		Self::refund_deposit(proposal_hash);
+		<QuorumCarried<T>>::remove(proposal_hash);
+		<PostponementsOf<T>>::remove(proposal_hash);
+		<DependsOn<T>>::remove(proposal_hash);
+		<ElevationConditionOf<T>>::remove(proposal_hash);
+		<EnactDelayOf<T>>::remove(proposal_hash);
+		for (c, _) in <Council<T>>::active_council() {
+			<CouncilVoteOf<T>>::remove((*proposal_hash, c));
+		}
+	}
+
 	fn set_veto_of(proposal: &T::Hash, expiry: T::BlockNumber, vetoers: Vec<T::AccountId>) {
 		<VetoedProposal<T>>::insert(proposal, (expiry, vetoers));
 	}
@@ -168,8 +814,11 @@ impl<T: Trait> Module<T> {
 		<VetoedProposal<T>>::remove(proposal);
 	}
 
-	fn take_tally(proposal_hash: &T::Hash) -> (u32, u32, u32) {
-		Self::generic_tally(proposal_hash, |w: &T::AccountId, p: &T::Hash| <CouncilVoteOf<T>>::take((*p, w.clone())))
+	fn remove_votes(proposal_hash: &T::Hash) {
+		for (c, _) in <Council<T>>::active_council() {
+			<CouncilVoteOf<T>>::remove((*proposal_hash, c));
+		}
+		<CachedTally<T>>::remove(proposal_hash);
 	}
 
 	fn generic_tally<F: Fn(&T::AccountId, &T::Hash) -> Option<bool>>(proposal_hash: &T::Hash, vote_of: F) -> (u32, u32, u32) {
@@ -197,22 +846,77 @@ impl<T: Trait> Module<T> {
 		}
 	}
 
+	/// Queue a proposal taken at block `now` for tallying again one voting period later.
+	fn requeue(now: T::BlockNumber, proposal: T::Proposal, proposal_hash: T::Hash) {
+		let mut proposals = Self::proposals();
+		proposals.push((now + Self::voting_period(), proposal_hash));
+		proposals.sort_by_key(|&(expiry, _)| expiry);
+		Self::set_proposals(&proposals);
+		<ProposalOf<T>>::insert(proposal_hash, proposal);
+	}
+
 	fn end_block(now: T::BlockNumber) -> Result {
+		Self::prune_delegations();
+		Self::end_multi_proposals(now);
 		while let Some((proposal, proposal_hash)) = Self::take_proposal_if_expiring_at(now) {
-			let tally = Self::take_tally(&proposal_hash);
+			let (approve, reject, abstain) = Self::tally(&proposal_hash);
+			let quorum_met = Self::quorum_met(&proposal_hash, approve, reject);
+			let postponement_threshold = Self::postponement_threshold();
+			if !Self::voting_period().is_zero() {
+				if !quorum_met && !Self::quorum_carried(&proposal_hash) {
+					// give it (only) one more voting period to gather enough votes.
+					<QuorumCarried<T>>::insert(&proposal_hash, true);
+					Self::requeue(now, proposal, proposal_hash);
+					Self::deposit_event(RawEvent::QuorumNotMetCarried(proposal_hash));
+					continue;
+				}
+				if postponement_threshold > 0 && abstain >= postponement_threshold
+					&& Self::postponements_of(&proposal_hash) < MAX_POSTPONEMENTS
+				{
+					<PostponementsOf<T>>::mutate(&proposal_hash, |n| *n += 1);
+					Self::requeue(now, proposal, proposal_hash);
+					Self::deposit_event(RawEvent::Postponed(proposal_hash));
+					continue;
+				}
+			}
+			<QuorumCarried<T>>::remove(&proposal_hash);
+			<PostponementsOf<T>>::remove(&proposal_hash);
+			Self::refund_deposit(&proposal_hash);
+			let dependency = <DependsOn<T>>::take(&proposal_hash);
+			let condition = <ElevationConditionOf<T>>::take(&proposal_hash);
+			let enact_delay = <EnactDelayOf<T>>::take(&proposal_hash);
+
+			let weighted_tally = Self::weighted_tally(&proposal_hash);
+			let tally = (approve, reject, abstain);
+			Self::remove_votes(&proposal_hash);
 			if let Some(&democracy::Call::cancel_referendum(ref_index)) = IsSubType::<democracy::Module<T>>::is_aux_sub_type(&proposal) {
 				Self::deposit_event(RawEvent::TallyCancelation(proposal_hash, tally.0, tally.1, tally.2));
-				if let (_, 0, 0) = tally {
+				if let (true, (_, 0, 0)) = (quorum_met, tally) {
 					<democracy::Module<T>>::internal_cancel_referendum(ref_index.into());
 				}
 			} else {
 				Self::deposit_event(RawEvent::TallyReferendum(proposal_hash.clone(), tally.0, tally.1, tally.2));
-				if tally.0 > tally.1 + tally.2 {
+				let passed = match weighted_tally {
+					Some((approve, reject, abstain)) => approve > reject.saturating_add(abstain),
+					None => tally.0 > tally.1 + tally.2,
+				};
+				if quorum_met && passed {
+					if let Some(dependency) = dependency.filter(|d| !Self::has_passed(d)) {
+						Self::deposit_event(RawEvent::DependencyUnmet(proposal_hash, dependency));
+						continue;
+					}
+					if condition.map_or(false, |c| !c.holds(&now)) {
+						Self::deposit_event(RawEvent::ElevationConditionFailed(proposal_hash, now));
+						continue;
+					}
 					Self::kill_veto_of(&proposal_hash);
-					// If there were no nay-votes from the council, then it's weakly uncontroversial; we enact immediately.
-					let period = match tally.1 {
-						0 => Zero::zero(),
-						_ => Self::enact_delay_period(),
+					<PassedProposals<T>>::insert(&proposal_hash, true);
+					// A delay asked for by the proposer always stands. Otherwise, if there were no nay-votes
+					// from the council, then it's weakly uncontroversial; we enact immediately.
+					let period = match (enact_delay, tally.1) {
+						(Some(delay), _) => delay,
+						(None, 0) => Zero::zero(),
+						(None, _) => Self::enact_delay_period(),
 					};
 					// If all council members voted yes, then it's strongly uncontroversial; we require a negative
 					// super-majority at referendum in order to defeat it.
@@ -253,6 +957,8 @@ mod tests {
 			assert_eq!(CouncilVoting::is_vetoed(&H256::default()), false);
 			assert_eq!(CouncilVoting::vote_of((H256::default(), 1)), None);
 			assert_eq!(CouncilVoting::tally(&H256::default()), (0, 0, 3));
+			assert_eq!(CouncilVoting::weighted_tally(&H256::default()), None);
+			assert_eq!(CouncilVoting::quorum(), 0);
 		});
 	}
 
@@ -408,87 +1114,925 @@ mod tests {
 	}
 
 	#[test]
-	fn simple_propose_should_work() {
+	fn cooloff_should_grow_with_vetoes_up_to_max() {
 		with_externalities(&mut new_test_ext(true), || {
+			assert_eq!(CouncilVoting::cooloff_after_vetoes(0), 2);
+			assert_eq!(CouncilVoting::cooloff_after_vetoes(1), 4);
+			assert_eq!(CouncilVoting::cooloff_after_vetoes(2), 5);
+			assert_eq!(CouncilVoting::cooloff_after_vetoes(u32::max_value()), 5);
+
 			System::set_block_number(1);
 			let proposal = set_balance_proposal(42);
 			let hash = proposal.blake2_256().into();
 			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
-			assert_eq!(CouncilVoting::proposals().len(), 1);
-			assert_eq!(CouncilVoting::proposal_voters(&hash), vec![1]);
-			assert_eq!(CouncilVoting::vote_of((hash, 1)), Some(true));
-			assert_eq!(CouncilVoting::tally(&hash), (1, 0, 2));
+			assert_ok!(CouncilVoting::veto(Origin::signed(2), hash));
+			assert_eq!(CouncilVoting::veto_of(&hash), Some((3, vec![2])));
+
+			System::set_block_number(3);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::veto(Origin::signed(3), hash));
+			assert_eq!(CouncilVoting::veto_of(&hash), Some((7, vec![2, 3])));
+
+			System::set_block_number(7);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::veto(Origin::signed(1), hash));
+			assert_eq!(CouncilVoting::veto_of(&hash), Some((12, vec![1, 2, 3])));
 		});
 	}
 
 	#[test]
-	fn unvoted_proposal_should_expire_without_action() {
+	fn abstentions_below_postponement_threshold_should_not_postpone() {
 		with_externalities(&mut new_test_ext(true), || {
 			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_postponement_threshold(2));
 			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
 			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
-			assert_eq!(CouncilVoting::tally(&proposal.blake2_256().into()), (1, 0, 2));
-			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+			assert_eq!(CouncilVoting::tally(&hash), (2, 0, 1));
 
 			System::set_block_number(2);
 			assert_ok!(CouncilVoting::end_block(System::block_number()));
 			assert_eq!(CouncilVoting::proposals().len(), 0);
-			assert_eq!(Democracy::active_referenda().len(), 0);
+			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(5, set_balance_proposal(42), VoteThreshold::SimpleMajority, 0))]);
 		});
 	}
 
 	#[test]
-	fn unanimous_proposal_should_expire_with_biased_referendum() {
+	fn abstentions_at_postponement_threshold_should_postpone() {
 		with_externalities(&mut new_test_ext(true), || {
 			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_postponement_threshold(2));
 			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
 			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
-			assert_ok!(CouncilVoting::vote(Origin::signed(2), proposal.blake2_256().into(), true));
-			assert_ok!(CouncilVoting::vote(Origin::signed(3), proposal.blake2_256().into(), true));
-			assert_eq!(CouncilVoting::tally(&proposal.blake2_256().into()), (3, 0, 0));
-			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 0, 2));
 
 			System::set_block_number(2);
 			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals(), vec![(3, hash)]);
+			assert_eq!(CouncilVoting::proposal_of(&hash), Some(set_balance_proposal(42)));
+			assert_eq!(Democracy::active_referenda().len(), 0);
+
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, true));
+			System::set_block_number(3);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
 			assert_eq!(CouncilVoting::proposals().len(), 0);
-			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(5, proposal, VoteThreshold::SuperMajorityAgainst, 0))]);
+			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(6, set_balance_proposal(42), VoteThreshold::SuperMajorityAgainst, 0))]);
 		});
 	}
 
 	#[test]
-	fn majority_proposal_should_expire_with_unbiased_referendum() {
+	fn proposal_deposit_should_scale_with_size() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_proposal_deposit(1, 1));
+			assert_eq!(CouncilVoting::proposal_deposit(0), 1);
+			assert_eq!(CouncilVoting::proposal_deposit(10), 11);
+			assert_eq!(CouncilVoting::proposal_deposit(100), 101);
+
+			let small = set_balance_proposal(42);
+			let large = Call::Democracy(democracy::Call::propose(Box::new(set_balance_proposal(42)), 0));
+			let small_deposit = 1 + small.encode().len() as u64;
+			let large_deposit = 1 + large.encode().len() as u64;
+			assert!(large_deposit > small_deposit);
+
+			assert_ok!(CouncilVoting::propose(Origin::signed(3), Box::new(small.clone())));
+			assert_eq!(Balances::reserved_balance(&3), small_deposit);
+			assert_ok!(CouncilVoting::propose(Origin::signed(3), Box::new(large.clone())));
+			assert_eq!(Balances::reserved_balance(&3), small_deposit + large_deposit);
+			assert_noop!(
+				CouncilVoting::propose(Origin::signed(1), Box::new(set_balance_proposal(43))),
+				"proposer's balance too low"
+			);
+
+			assert_ok!(CouncilVoting::veto(Origin::signed(2), large.blake2_256().into()));
+			assert_eq!(Balances::reserved_balance(&3), small_deposit);
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(Balances::reserved_balance(&3), 0);
+			assert_eq!(Balances::free_balance(&3), 30);
+		});
+	}
+
+	#[test]
+	fn stake_weighted_votes_should_decide_elevation() {
 		with_externalities(&mut new_test_ext(true), || {
+			STAKE_WEIGHTED_VOTES.with(|w| *w.borrow_mut() = true);
+			<seats::BackingStakeOf<Test>>::insert(1, 100);
+			<seats::BackingStakeOf<Test>>::insert(2, 10);
+			<seats::BackingStakeOf<Test>>::insert(3, 10);
+
 			System::set_block_number(1);
 			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
 			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
-			assert_ok!(CouncilVoting::vote(Origin::signed(2), proposal.blake2_256().into(), true));
-			assert_ok!(CouncilVoting::vote(Origin::signed(3), proposal.blake2_256().into(), false));
-			assert_eq!(CouncilVoting::tally(&proposal.blake2_256().into()), (2, 1, 0));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, false));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, false));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 2, 0));
+			assert_eq!(CouncilVoting::weighted_tally(&hash), Some((100, 20, 0)));
+
+			System::set_block_number(2);
 			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(5, set_balance_proposal(42), VoteThreshold::SimpleMajority, 0))]);
+		});
+	}
+
+	#[test]
+	fn stake_weighted_abstentions_should_count_against() {
+		with_externalities(&mut new_test_ext(true), || {
+			STAKE_WEIGHTED_VOTES.with(|w| *w.borrow_mut() = true);
+			<seats::BackingStakeOf<Test>>::insert(1, 100);
+			<seats::BackingStakeOf<Test>>::insert(2, 10);
+			<seats::BackingStakeOf<Test>>::insert(3, 10);
+
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(2), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, true));
+			assert_eq!(CouncilVoting::tally(&hash), (2, 0, 1));
+			assert_eq!(CouncilVoting::weighted_tally(&hash), Some((20, 0, 100)));
 
 			System::set_block_number(2);
 			assert_ok!(CouncilVoting::end_block(System::block_number()));
-			assert_eq!(CouncilVoting::proposals().len(), 0);
-			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(5, proposal, VoteThreshold::SimpleMajority, 0))]);
+			assert_eq!(Democracy::active_referenda().len(), 0);
 		});
 	}
 
 	#[test]
-	fn propose_by_public_should_not_work() {
+	fn cached_tally_should_match_recomputed_tally() {
 		with_externalities(&mut new_test_ext(true), || {
 			System::set_block_number(1);
 			let proposal = set_balance_proposal(42);
-			assert_noop!(CouncilVoting::propose(Origin::signed(4), Box::new(proposal)), "proposer would not be on council");
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_eq!(CouncilVoting::cached_tally(&hash), (1, 0));
+			assert_eq!(CouncilVoting::tally(&hash), CouncilVoting::recomputed_tally(&hash));
+
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, false));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, false));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 2, 0));
+			assert_eq!(CouncilVoting::tally(&hash), CouncilVoting::recomputed_tally(&hash));
+
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(1), hash, false));
+			assert_ok!(CouncilVoting::vote(Origin::signed(1), hash, false));
+			assert_eq!(CouncilVoting::cached_tally(&hash), (1, 2));
+			assert_eq!(CouncilVoting::tally(&hash), CouncilVoting::recomputed_tally(&hash));
+
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, true));
+			assert_eq!(CouncilVoting::tally(&hash), (2, 1, 0));
+			assert_eq!(CouncilVoting::tally(&hash), CouncilVoting::recomputed_tally(&hash));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::cached_tally(&hash), (0, 0));
 		});
 	}
 
 	#[test]
-	fn vote_by_public_should_not_work() {
+	fn two_hop_delegation_should_resolve_to_final_voter() {
 		with_externalities(&mut new_test_ext(true), || {
 			System::set_block_number(1);
+			assert_ok!(CouncilVoting::delegate(Origin::signed(3), 2));
+			assert_ok!(CouncilVoting::delegate(Origin::signed(2), 1));
+
 			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
 			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
-			assert_noop!(CouncilVoting::vote(Origin::signed(4), proposal.blake2_256().into(), true), "only councillors may vote on council proposals");
+			assert_eq!(CouncilVoting::resolved_vote_of(&hash, &3), Some(true));
+			assert_eq!(CouncilVoting::tally(&hash), (3, 0, 0));
+
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, false));
+			assert_eq!(CouncilVoting::resolved_vote_of(&hash, &3), Some(false));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 2, 0));
+
+			assert_ok!(CouncilVoting::undelegate(Origin::signed(3)));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 1, 1));
+		});
+	}
+
+	#[test]
+	fn delegation_cycle_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_noop!(CouncilVoting::delegate(Origin::signed(1), 1), "delegation would form a cycle");
+			assert_ok!(CouncilVoting::delegate(Origin::signed(1), 2));
+			assert_ok!(CouncilVoting::delegate(Origin::signed(2), 3));
+			assert_noop!(CouncilVoting::delegate(Origin::signed(3), 1), "delegation would form a cycle");
+			assert_noop!(CouncilVoting::delegate(Origin::signed(4), 1), "only councillors may delegate");
+		});
+	}
+
+	#[test]
+	fn over_depth_delegation_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(Council::set_max_delegation_depth(1));
+			assert_ok!(CouncilVoting::delegate(Origin::signed(2), 3));
+			assert_noop!(CouncilVoting::delegate(Origin::signed(1), 2), "delegation chain too deep");
+			assert_ok!(CouncilVoting::delegate(Origin::signed(1), 3));
+		});
+	}
+
+	#[test]
+	fn simple_propose_should_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_eq!(CouncilVoting::proposals().len(), 1);
+			assert_eq!(CouncilVoting::proposal_voters(&hash), vec![1]);
+			assert_eq!(CouncilVoting::vote_of((hash, 1)), Some(true));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 0, 2));
+		});
+	}
+
+	#[test]
+	fn unvoted_proposal_should_expire_without_action() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_eq!(CouncilVoting::tally(&proposal.blake2_256().into()), (1, 0, 2));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(Democracy::active_referenda().len(), 0);
+		});
+	}
+
+	#[test]
+	fn unanimous_proposal_should_expire_with_biased_referendum() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), proposal.blake2_256().into(), true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), proposal.blake2_256().into(), true));
+			assert_eq!(CouncilVoting::tally(&proposal.blake2_256().into()), (3, 0, 0));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(5, proposal, VoteThreshold::SuperMajorityAgainst, 0))]);
+		});
+	}
+
+	#[test]
+	fn majority_proposal_should_expire_with_unbiased_referendum() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), proposal.blake2_256().into(), true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), proposal.blake2_256().into(), false));
+			assert_eq!(CouncilVoting::tally(&proposal.blake2_256().into()), (2, 1, 0));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(5, proposal, VoteThreshold::SimpleMajority, 0))]);
+		});
+	}
+
+	#[test]
+	fn propose_by_public_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_noop!(CouncilVoting::propose(Origin::signed(4), Box::new(proposal)), "proposer would not be on council");
+		});
+	}
+
+	#[test]
+	fn vote_by_public_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_noop!(CouncilVoting::vote(Origin::signed(4), proposal.blake2_256().into(), true), "only councillors may vote on council proposals");
+		});
+	}
+
+	#[test]
+	fn proposal_missing_quorum_should_be_carried_over_once() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_quorum(2));
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals(), vec![(3, hash)]);
+			assert_eq!(CouncilVoting::quorum_carried(&hash), true);
+			assert_eq!(CouncilVoting::tally(&hash), (1, 0, 2));
+			assert_eq!(Democracy::active_referenda().len(), 0);
+
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+
+			System::set_block_number(3);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(CouncilVoting::quorum_carried(&hash), false);
+			assert_eq!(Democracy::active_referenda(), vec![(0, ReferendumInfo::new(6, proposal, VoteThreshold::SimpleMajority, 0))]);
+		});
+	}
+
+	#[test]
+	fn proposal_missing_quorum_twice_should_be_rejected() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_quorum(2));
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals(), vec![(3, hash)]);
+
+			System::set_block_number(3);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(CouncilVoting::proposal_of(&hash), None);
+			assert_eq!(CouncilVoting::quorum_carried(&hash), false);
+			assert_eq!(Democracy::active_referenda().len(), 0);
+		});
+	}
+
+	#[test]
+	fn dependent_proposal_should_be_elevated_after_its_dependency() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let first = set_balance_proposal(42);
+			let first_hash = first.blake2_256().into();
+			let second = set_balance_proposal(43);
+			let second_hash = second.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(first.clone())));
+			assert_ok!(CouncilVoting::propose_with_options(Origin::signed(1), Box::new(second.clone()), Some(first_hash), None, None));
+			assert_eq!(CouncilVoting::depends_on(&second_hash), Some(first_hash));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), first_hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), second_hash, true));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert!(CouncilVoting::has_passed(&first_hash));
+			assert!(CouncilVoting::has_passed(&second_hash));
+			assert_eq!(CouncilVoting::depends_on(&second_hash), None);
+			assert_eq!(Democracy::active_referenda(), vec![
+				(0, ReferendumInfo::new(5, first, VoteThreshold::SimpleMajority, 0)),
+				(1, ReferendumInfo::new(5, second, VoteThreshold::SimpleMajority, 0)),
+			]);
+		});
+	}
+
+	#[test]
+	fn dependent_proposal_should_be_skipped_if_dependency_failed() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let first = set_balance_proposal(42);
+			let first_hash = first.blake2_256().into();
+			let second = set_balance_proposal(43);
+			let second_hash = second.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(first.clone())));
+			assert_ok!(CouncilVoting::propose_with_options(Origin::signed(1), Box::new(second.clone()), Some(first_hash), None, None));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), first_hash, false));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), second_hash, true));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert!(!CouncilVoting::has_passed(&first_hash));
+			assert!(!CouncilVoting::has_passed(&second_hash));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(Democracy::active_referenda().len(), 0);
+		});
+	}
+
+	#[test]
+	fn quorum_by_member_count_ignores_stake() {
+		with_externalities(&mut new_test_ext(true), || {
+			STAKE_WEIGHTED_VOTES.with(|w| *w.borrow_mut() = true);
+			<seats::BackingStakeOf<Test>>::insert(1, 100);
+			<seats::BackingStakeOf<Test>>::insert(2, 10);
+			<seats::BackingStakeOf<Test>>::insert(3, 10);
+			System::set_block_number(1);
+			assert_eq!(CouncilVoting::quorum_measure(), QuorumBy::MemberCount);
+			assert_ok!(CouncilVoting::set_quorum(2));
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals(), vec![(3, hash)]);
+			assert_eq!(CouncilVoting::quorum_carried(&hash), true);
+			assert_eq!(Democracy::active_referenda().len(), 0);
+		});
+	}
+
+	#[test]
+	fn quorum_by_backing_stake_counts_stake_of_voters() {
+		with_externalities(&mut new_test_ext(true), || {
+			STAKE_WEIGHTED_VOTES.with(|w| *w.borrow_mut() = true);
+			<seats::BackingStakeOf<Test>>::insert(1, 100);
+			<seats::BackingStakeOf<Test>>::insert(2, 10);
+			<seats::BackingStakeOf<Test>>::insert(3, 10);
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_quorum_measure(QuorumBy::BackingStake));
+			assert_ok!(CouncilVoting::set_stake_quorum(Perbill::from_percent(50)));
+			// the member count quorum no longer applies.
+			assert_ok!(CouncilVoting::set_quorum(3));
+			let proposal = set_balance_proposal(42);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			// the one high-stake councillor alone makes quorum.
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(Democracy::active_referenda().len(), 1);
+
+			// the low-stake councillors together don't.
+			let proposal = set_balance_proposal(43);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(2), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, true));
+
+			System::set_block_number(3);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals(), vec![(4, hash)]);
+			assert_eq!(CouncilVoting::quorum_carried(&hash), true);
+			assert_eq!(Democracy::active_referenda().len(), 1);
+		});
+	}
+
+	fn multi_options() -> Vec<H256> {
+		(1..4).map(|v| set_balance_proposal(v).blake2_256().into()).collect()
+	}
+
+	#[test]
+	fn multi_option_proposal_with_clear_winner_works() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let options = multi_options();
+			let hash: H256 = options.blake2_256().into();
+			assert_ok!(CouncilVoting::propose_multi(Origin::signed(1), options.clone()));
+			assert_eq!(CouncilVoting::multi_proposals(), vec![(2, hash)]);
+			assert_noop!(
+				CouncilVoting::rank_vote(Origin::signed(1), hash, vec![3]),
+				"ranking names an unknown option"
+			);
+			assert_noop!(
+				CouncilVoting::rank_vote(Origin::signed(1), hash, vec![0, 0]),
+				"ranking names an option twice"
+			);
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(1), hash, vec![0, 1]));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(2), hash, vec![0]));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(3), hash, vec![1, 2]));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::multi_proposals(), vec![]);
+			assert_eq!(CouncilVoting::ranking_of((hash, 1)), None);
+			assert_eq!(
+				System::events().last().unwrap().event,
+				crate::tests::Event::voting(RawEvent::MultiOptionChosen(hash, options[0]))
+			);
+		});
+	}
+
+	#[test]
+	fn multi_option_proposal_with_elimination_round_works() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			<seats::ActiveCouncil<Test>>::put(vec![(1, 10), (2, 10), (3, 10), (4, 10), (5, 10)]);
+			let options = multi_options();
+			let hash: H256 = options.blake2_256().into();
+			assert_ok!(CouncilVoting::propose_multi(Origin::signed(1), options.clone()));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(1), hash, vec![0]));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(2), hash, vec![0]));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(3), hash, vec![1]));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(4), hash, vec![2, 1]));
+			assert_ok!(CouncilVoting::rank_vote(Origin::signed(5), hash, vec![1]));
+
+			// option 2 is eliminated first and its ballot makes option 1 the majority choice.
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(
+				System::events().last().unwrap().event,
+				crate::tests::Event::voting(RawEvent::MultiOptionChosen(hash, options[1]))
+			);
+
+			// ties are broken in favour of the earlier option.
+			assert_eq!(CouncilVoting::instant_runoff(2, &[vec![0], vec![1]]), Some(0));
+			assert_eq!(CouncilVoting::instant_runoff(2, &[]), None);
+		});
+	}
+
+	#[test]
+	fn amending_proposal_before_votes_should_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_proposal_deposit(5, 0));
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			let amended = set_balance_proposal(43);
+			let amended_hash: H256 = amended.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_noop!(
+				CouncilVoting::amend_proposal(Origin::signed(2), hash, Box::new(amended.clone())),
+				"only the proposer may amend a proposal"
+			);
+
+			assert_ok!(CouncilVoting::amend_proposal(Origin::signed(1), hash, Box::new(amended.clone())));
+			assert_eq!(CouncilVoting::proposals(), vec![(2, amended_hash)]);
+			assert_eq!(CouncilVoting::proposal_of(&hash), None);
+			assert_eq!(CouncilVoting::proposal_of(&amended_hash), Some(amended));
+			assert_eq!(CouncilVoting::proposal_voters(&amended_hash), vec![1]);
+			assert_eq!(CouncilVoting::tally(&amended_hash), (1, 0, 2));
+			assert_eq!(CouncilVoting::deposit_of(&amended_hash), Some((1, 5)));
+			assert_eq!(Balances::reserved_balance(&1), 5);
+		});
+	}
+
+	#[test]
+	fn amending_proposal_after_votes_should_fail() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, false));
+
+			assert_noop!(
+				CouncilVoting::amend_proposal(Origin::signed(1), hash, Box::new(set_balance_proposal(43))),
+				"proposal already voted on"
+			);
+			assert_eq!(CouncilVoting::proposal_of(&hash), Some(proposal));
+		});
+	}
+
+	#[test]
+	fn conditional_proposal_should_be_elevated_while_condition_holds() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose_with_options(
+				Origin::signed(1), Box::new(proposal.clone()), None, Some(ElevationCondition::Before(3)), None
+			));
+			assert_eq!(CouncilVoting::elevation_condition_of(&hash), Some(ElevationCondition::Before(3)));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert!(CouncilVoting::has_passed(&hash));
+			assert_eq!(CouncilVoting::elevation_condition_of(&hash), None);
+			assert_eq!(Democracy::active_referenda(), vec![
+				(0, ReferendumInfo::new(5, proposal, VoteThreshold::SimpleMajority, 0)),
+			]);
+		});
+	}
+
+	#[test]
+	fn conditional_proposal_should_be_skipped_once_condition_fails() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose_with_options(
+				Origin::signed(1), Box::new(proposal), None, Some(ElevationCondition::Before(2)), None
+			));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert!(!CouncilVoting::has_passed(&hash));
+			assert_eq!(CouncilVoting::elevation_condition_of(&hash), None);
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(Democracy::active_referenda().len(), 0);
+			assert_eq!(
+				System::events().last().unwrap().event,
+				crate::tests::Event::voting(RawEvent::ElevationConditionFailed(hash, 2))
+			);
+		});
+	}
+
+	#[test]
+	fn unanimous_cancellations_should_cancel_in_bulk() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let first = set_balance_proposal(42);
+			let second = set_balance_proposal(43);
+			assert_ok!(Democracy::internal_start_referendum(first, VoteThreshold::SuperMajorityApprove, 0), 0);
+			assert_ok!(Democracy::internal_start_referendum(second.clone(), VoteThreshold::SuperMajorityApprove, 0), 1);
+
+			let unanimous = cancel_referendum_proposal(0);
+			let unanimous_hash = unanimous.blake2_256().into();
+			let contested = cancel_referendum_proposal(1);
+			let contested_hash = contested.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(unanimous)));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), unanimous_hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), unanimous_hash, true));
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(contested)));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), contested_hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), contested_hash, false));
+
+			assert_noop!(CouncilVoting::cancel_referenda(Origin::signed(4), vec![0]), "only councillors may cancel referenda");
+			assert_ok!(CouncilVoting::cancel_referenda(Origin::signed(1), vec![0, 1, 2]));
+			assert_eq!(
+				Democracy::active_referenda(),
+				vec![(1, ReferendumInfo::new(4, second, VoteThreshold::SuperMajorityApprove, 0))]
+			);
+			assert_eq!(CouncilVoting::proposals(), vec![(2, contested_hash)]);
+			assert_eq!(CouncilVoting::proposal_of(&unanimous_hash), None);
+			assert_eq!(CouncilVoting::vote_of((unanimous_hash, 1)), None);
+
+			let results = System::events().into_iter()
+				.filter_map(|r| match r.event {
+					crate::tests::Event::voting(RawEvent::ReferendumCancellation(i, c)) => Some((i, c)),
+					_ => None,
+				})
+				.collect::<Vec<_>>();
+			assert_eq!(results, vec![(0, true), (1, false), (2, false)]);
+		});
+	}
+
+	#[test]
+	fn veto_override_by_supermajority_should_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_noop!(CouncilVoting::override_veto(Origin::signed(1), hash), "proposal is not vetoed");
+			assert_ok!(CouncilVoting::veto(Origin::signed(2), hash));
+
+			assert_noop!(CouncilVoting::override_veto(Origin::signed(4), hash), "only councillors may override vetoes");
+			assert_ok!(CouncilVoting::override_veto(Origin::signed(1), hash));
+			assert_noop!(
+				CouncilVoting::override_veto(Origin::signed(1), hash),
+				"a councillor may not co-sign an override twice"
+			);
+			assert!(CouncilVoting::is_vetoed(&hash));
+			assert_eq!(CouncilVoting::veto_overrides(&hash), vec![1]);
+
+			// two of three councillors fall short of 67%.
+			assert_ok!(CouncilVoting::override_veto(Origin::signed(3), hash));
+			assert!(CouncilVoting::is_vetoed(&hash));
+			assert_eq!(CouncilVoting::veto_overrides(&hash), vec![1, 3]);
+
+			assert_ok!(CouncilVoting::override_veto(Origin::signed(2), hash));
+			assert!(!CouncilVoting::is_vetoed(&hash));
+			assert_eq!(CouncilVoting::veto_overrides(&hash), Vec::<u64>::new());
+			assert_eq!(
+				System::events().last().unwrap().event,
+				crate::tests::Event::voting(RawEvent::VetoOverridden(hash))
+			);
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal)));
+		});
+	}
+
+	#[test]
+	fn proposal_with_shorter_enact_delay_should_be_elevated_with_it() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			<EnactDelayPeriod<Test>>::put(5);
+			let urgent = set_balance_proposal(42);
+			let urgent_hash = urgent.blake2_256().into();
+			let routine = set_balance_proposal(43);
+			let routine_hash = routine.blake2_256().into();
+			assert_ok!(CouncilVoting::propose_with_options(Origin::signed(1), Box::new(urgent.clone()), None, None, Some(2)));
+			assert_eq!(CouncilVoting::enact_delay_of(&urgent_hash), Some(2));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), urgent_hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), urgent_hash, false));
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(routine.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), routine_hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), routine_hash, false));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::enact_delay_of(&urgent_hash), None);
+			assert_eq!(Democracy::active_referenda(), vec![
+				(0, ReferendumInfo::new(5, urgent, VoteThreshold::SimpleMajority, 2)),
+				(1, ReferendumInfo::new(5, routine, VoteThreshold::SimpleMajority, 5)),
+			]);
+		});
+	}
+
+	#[test]
+	fn proposal_with_enact_delay_below_minimum_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_noop!(
+				CouncilVoting::propose_with_options(Origin::signed(1), Box::new(set_balance_proposal(42)), None, None, Some(0)),
+				"enactment delay too short"
+			);
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+		});
+	}
+
+	#[test]
+	fn veto_of_cooloff_exempt_proposal_should_not_postpone() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::set_cooloff_exempt(hash, true));
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::veto(Origin::signed(2), hash));
+			assert_noop!(CouncilVoting::veto(Origin::signed(2), hash), "a councillor may not veto a proposal twice");
+
+			assert_eq!(CouncilVoting::veto_of(&hash), Some((1, vec![2])));
+			assert!(!CouncilVoting::is_vetoed(&hash));
+			assert_eq!(CouncilVoting::proposals(), vec![(2, hash)]);
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, true));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(Democracy::active_referenda(), vec![
+				(0, ReferendumInfo::new(5, proposal, VoteThreshold::SimpleMajority, 0)),
+			]);
+		});
+	}
+
+	#[test]
+	fn veto_of_proposal_no_longer_exempt_should_postpone() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::set_cooloff_exempt(hash, true));
+			assert_ok!(CouncilVoting::set_cooloff_exempt(hash, false));
+			assert!(!CouncilVoting::is_cooloff_exempt(&hash));
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::veto(Origin::signed(2), hash));
+
+			assert_eq!(CouncilVoting::veto_of(&hash), Some((3, vec![2])));
+			assert!(CouncilVoting::is_vetoed(&hash));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_noop!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal)), "proposal is vetoed");
+		});
+	}
+
+	#[test]
+	fn tally_should_not_count_votes_of_departed_councillors() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+			assert_eq!(CouncilVoting::tally(&hash), (2, 0, 1));
+
+			assert_ok!(Council::remove_member(2));
+			assert_eq!(CouncilVoting::cached_tally(&hash), (2, 0));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 0, 1));
+			assert_eq!(CouncilVoting::tally(&hash), CouncilVoting::recomputed_tally(&hash));
+
+			// rather than being elevated as unanimous, it falls short of a majority.
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(Democracy::active_referenda().len(), 0);
+		});
+	}
+
+	#[test]
+	fn amending_should_rescale_the_deposit() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_proposal_deposit(1, 1));
+			let small = set_balance_proposal(42);
+			let large = Call::Democracy(democracy::Call::propose(Box::new(set_balance_proposal(42)), 0));
+			let small_deposit = 1 + small.encode().len() as u64;
+			let large_deposit = 1 + large.encode().len() as u64;
+
+			assert_ok!(CouncilVoting::propose(Origin::signed(3), Box::new(small.clone())));
+			assert_eq!(Balances::reserved_balance(&3), small_deposit);
+			assert_ok!(CouncilVoting::amend_proposal(Origin::signed(3), small.blake2_256().into(), Box::new(large.clone())));
+			assert_eq!(Balances::reserved_balance(&3), large_deposit);
+			assert_eq!(CouncilVoting::deposit_of(&large.blake2_256().into()), Some((3, large_deposit)));
+
+			assert_ok!(CouncilVoting::amend_proposal(Origin::signed(3), large.blake2_256().into(), Box::new(small.clone())));
+			assert_eq!(Balances::reserved_balance(&3), small_deposit);
+
+			// a proposer who cannot afford the larger deposit keeps the smaller proposal.
+			assert_ok!(CouncilVoting::set_proposal_deposit(6, 1));
+			assert_ok!(CouncilVoting::propose(Origin::signed(2), Box::new(set_balance_proposal(43))));
+			assert_noop!(
+				CouncilVoting::amend_proposal(Origin::signed(2), set_balance_proposal(43).blake2_256().into(), Box::new(large)),
+				"proposer's balance too low"
+			);
+		});
+	}
+
+	#[test]
+	fn requested_enact_delay_should_stand_without_nay_votes() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose_with_options(Origin::signed(1), Box::new(proposal.clone()), None, None, Some(3)));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+			assert_ok!(CouncilVoting::vote(Origin::signed(3), hash, true));
+
+			System::set_block_number(2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(Democracy::active_referenda(), vec![
+				(0, ReferendumInfo::new(5, proposal, VoteThreshold::SuperMajorityAgainst, 3)),
+			]);
+		});
+	}
+
+	#[test]
+	fn postponement_should_be_capped() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::set_postponement_threshold(2));
+			assert_ok!(CouncilVoting::set_proposal_deposit(1, 0));
+			let proposal = set_balance_proposal(42);
+			let hash = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal.clone())));
+			assert_eq!(Balances::reserved_balance(&1), 1);
+
+			for n in 1..=MAX_POSTPONEMENTS {
+				System::set_block_number(n as u64 + 1);
+				assert_ok!(CouncilVoting::end_block(System::block_number()));
+				assert_eq!(CouncilVoting::proposals(), vec![(n as u64 + 2, hash)]);
+				assert_eq!(CouncilVoting::postponements_of(&hash), n);
+			}
+
+			System::set_block_number(MAX_POSTPONEMENTS as u64 + 2);
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::proposals().len(), 0);
+			assert_eq!(CouncilVoting::postponements_of(&hash), 0);
+			assert_eq!(Balances::reserved_balance(&1), 0);
+			assert_eq!(Democracy::active_referenda().len(), 0);
+		});
+	}
+
+	#[test]
+	fn vote_on_unknown_proposal_should_not_work() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_noop!(CouncilVoting::vote(Origin::signed(2), hash, true), "proposal must exist");
+			assert_eq!(CouncilVoting::cached_tally(&hash), (0, 0));
+
+			// had the early vote been kept, proposing would reset the cache but not the vote.
+			assert_ok!(CouncilVoting::propose(Origin::signed(1), Box::new(proposal)));
+			assert_ok!(CouncilVoting::vote(Origin::signed(2), hash, true));
+			assert_eq!(CouncilVoting::tally(&hash), CouncilVoting::recomputed_tally(&hash));
+		});
+	}
+
+	#[test]
+	fn delegation_extending_a_chain_to_the_sender_should_respect_depth() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(Council::set_max_delegation_depth(1));
+			assert_ok!(CouncilVoting::delegate(Origin::signed(1), 2));
+			assert_noop!(CouncilVoting::delegate(Origin::signed(2), 3), "delegation chain too deep");
+
+			assert_ok!(Council::set_max_delegation_depth(2));
+			assert_ok!(CouncilVoting::delegate(Origin::signed(2), 3));
+		});
+	}
+
+	#[test]
+	fn delegations_of_departed_councillors_should_be_dropped() {
+		with_externalities(&mut new_test_ext(true), || {
+			System::set_block_number(1);
+			assert_ok!(CouncilVoting::delegate(Origin::signed(1), 2));
+			assert_ok!(CouncilVoting::delegate(Origin::signed(3), 1));
+			assert_eq!(CouncilVoting::delegation_count(), 2);
+
+			assert_ok!(Council::remove_member(1));
+			assert_ok!(CouncilVoting::end_block(System::block_number()));
+			assert_eq!(CouncilVoting::delegate_of(1), None);
+			assert_eq!(CouncilVoting::delegate_of(3), None);
+			assert_eq!(CouncilVoting::delegation_count(), 0);
+
+			// with no one delegating, the running vote count is used again.
+			let proposal = set_balance_proposal(42);
+			let hash: H256 = proposal.blake2_256().into();
+			assert_ok!(CouncilVoting::propose(Origin::signed(2), Box::new(proposal)));
+			assert_eq!(CouncilVoting::tally(&hash), (1, 0, 1));
 		});
 	}
 }
@@ -249,7 +249,8 @@
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use srml_support::{StorageValue, dispatch::Result, decl_module, decl_storage, decl_event};
+use srml_support::{StorageValue, StorageMap, dispatch::Result, decl_module, decl_storage, decl_event, ensure};
+use sr_primitives::traits::Hash;
 use system::ensure_signed;
 
 /// Our module's configuration trait. All our types and consts go in here. If the
@@ -297,6 +298,14 @@ decl_storage! {
 
 		// this one uses the default, we'll demonstrate the usage of 'mutate' API.
 		Foo get(foo) config(): T::Balance;
+
+		// A commitment to a value not yet revealed, with the block it was made at. This lets an
+		// offchain worker post oracle data without it being front-run: the value only becomes
+		// known once the commitment is already on chain.
+		pub Commitments get(commitment_of): map T::AccountId => Option<(T::BlockNumber, T::Hash)>;
+
+		// The value most recently revealed by each account.
+		pub Revealed get(revealed): map T::AccountId => Option<u64>;
 	}
 }
 
@@ -304,10 +313,14 @@ decl_event!(
 	/// Events are a simple means of reporting specific conditions and
 	/// circumstances that have happened that users, Dapps and/or chain explorers would find
 	/// interesting and otherwise difficult to detect.
-	pub enum Event<T> where B = <T as balances::Trait>::Balance {
+	pub enum Event<T> where B = <T as balances::Trait>::Balance, A = <T as system::Trait>::AccountId {
 		// Just a normal `enum`, here's a dummy event to ensure it compiles.
 		/// Dummy event, just here so there's a generic type that's used.
 		Dummy(B),
+		/// An account committed to a value it will reveal later.
+		Committed(A),
+		/// An account revealed the value it committed to.
+		Revealed(A, u64),
 	}
 );
 
@@ -432,6 +445,32 @@ decl_module! {
 			<Dummy<T>>::put(new_value);
 		}
 
+		/// Commit to a value without revealing it, by its `commitment` (see `Module::commitment`).
+		// This is the first half of a commit-reveal scheme. Any earlier commitment of the sender
+		// that was never revealed is replaced.
+		fn commit(origin, commitment: T::Hash) {
+			let sender = ensure_signed(origin)?;
+			let now = <system::Module<T>>::block_number();
+			<Commitments<T>>::insert(&sender, (now, commitment));
+			Self::deposit_event(RawEvent::Committed(sender));
+		}
+
+		/// Reveal the `value` committed to, along with the `salt` used in the commitment.
+		// The second half of the scheme. It must happen in a later block than the commitment,
+		// otherwise the commitment would give no protection at all.
+		fn reveal(origin, value: u64, salt: u64) -> Result {
+			let sender = ensure_signed(origin)?;
+
+			let (committed_at, commitment) = Self::commitment_of(&sender).ok_or("no commitment to reveal")?;
+			ensure!(<system::Module<T>>::block_number() > committed_at, "cannot reveal in the block of the commitment");
+			ensure!(Self::commitment(&sender, value, salt) == commitment, "revealed value does not match commitment");
+
+			<Commitments<T>>::remove(&sender);
+			<Revealed<T>>::insert(&sender, value);
+			Self::deposit_event(RawEvent::Revealed(sender, value));
+			Ok(())
+		}
+
 		// The signature could also look like: `fn on_initialize()`
 		fn on_initialize(_n: T::BlockNumber) {
 			// Anything that needs to be done at the start of the block.
@@ -452,6 +491,10 @@ decl_module! {
 			// We don't do anything here.
 			// but we could dispatch extrinsic (transaction/unsigned/inherent) using
 			// runtime_io::submit_extrinsic
+			//
+			// A worker reporting oracle data would submit `commit` with
+			// `Self::commitment(&who, value, salt)` for some random salt, remember both, and submit
+			// `reveal` with them in a later block.
 		}
 	}
 }
@@ -463,6 +506,14 @@ decl_module! {
 // - Private functions. These are your usual private utilities unavailable to other modules.
 impl<T: Trait> Module<T> {
 	// Add public immutables and private mutables.
+
+	/// The commitment to `value` that `reveal` by `who` with the same `salt` will accept.
+	// Binding the committer in stops anyone from copying a commitment they saw and revealing
+	// the same value once the committer has revealed it.
+	pub fn commitment(who: &T::AccountId, value: u64, salt: u64) -> T::Hash {
+		T::Hashing::hash_of(&(who, value, salt))
+	}
+
 	#[allow(dead_code)]
 	fn accumulate_foo(origin: T::Origin, increase_by: T::Balance) -> Result {
 		let _sender = ensure_signed(origin)?;
@@ -483,7 +534,7 @@ impl<T: Trait> Module<T> {
 mod tests {
 	use super::*;
 
-	use srml_support::{impl_outer_origin, assert_ok};
+	use srml_support::{impl_outer_origin, assert_ok, assert_noop};
 	use sr_io::with_externalities;
 	use substrate_primitives::{H256, Blake2Hasher};
 	// The testing primitives are very useful for avoiding having to work with signatures
@@ -527,6 +578,7 @@ mod tests {
 	impl Trait for Test {
 		type Event = ();
 	}
+	type System = system::Module<Test>;
 	type Example = Module<Test>;
 
 	// This function basically just builds a genesis storage key/value store according to
@@ -573,4 +625,37 @@ mod tests {
 			assert_eq!(Example::foo(), 25);
 		});
 	}
+
+	#[test]
+	fn matching_reveal_should_work() {
+		with_externalities(&mut new_test_ext(), || {
+			System::set_block_number(1);
+			assert_ok!(Example::commit(Origin::signed(1), Example::commitment(&1, 7, 1234)));
+			assert_noop!(Example::reveal(Origin::signed(1), 7, 1234), "cannot reveal in the block of the commitment");
+
+			System::set_block_number(2);
+			assert_ok!(Example::reveal(Origin::signed(1), 7, 1234));
+			assert_eq!(Example::revealed(1), Some(7));
+			assert_eq!(Example::commitment_of(1), None);
+		});
+	}
+
+	#[test]
+	fn mismatched_reveal_should_not_work() {
+		with_externalities(&mut new_test_ext(), || {
+			System::set_block_number(1);
+			assert_noop!(Example::reveal(Origin::signed(1), 7, 1234), "no commitment to reveal");
+			assert_ok!(Example::commit(Origin::signed(1), Example::commitment(&1, 7, 1234)));
+
+			System::set_block_number(2);
+			assert_noop!(Example::reveal(Origin::signed(1), 8, 1234), "revealed value does not match commitment");
+			assert_noop!(Example::reveal(Origin::signed(1), 7, 4321), "revealed value does not match commitment");
+			assert_eq!(Example::revealed(1), None);
+
+			// another account copying the commitment can't reveal it.
+			assert_ok!(Example::commit(Origin::signed(2), Example::commitment(&1, 7, 1234)));
+			System::set_block_number(3);
+			assert_noop!(Example::reveal(Origin::signed(2), 7, 1234), "revealed value does not match commitment");
+		});
+	}
 }